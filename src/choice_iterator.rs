@@ -0,0 +1,94 @@
+extern crate tesseract_sys;
+
+use self::tesseract_sys::{
+    TessChoiceIteratorConfidence, TessChoiceIteratorDelete, TessChoiceIteratorGetUTF8Text,
+    TessChoiceIteratorNext,
+};
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::os::raw::c_float;
+
+/// Wrapper around [`TessChoiceIterator`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html)
+///
+/// Iterates over the alternative hypotheses for the symbol a `ResultIterator` is positioned at.
+/// Borrows the `ResultIterator` so it can't be moved whilst the choices are being read.
+#[derive(Debug)]
+pub struct ChoiceIterator<'r>(
+    *mut tesseract_sys::TessChoiceIterator,
+    PhantomData<&'r tesseract_sys::TessResultIterator>,
+);
+
+unsafe impl Send for ChoiceIterator<'_> {}
+
+impl Drop for ChoiceIterator<'_> {
+    fn drop(&mut self) {
+        unsafe { TessChoiceIteratorDelete(self.0) }
+    }
+}
+
+impl<'r> ChoiceIterator<'r> {
+    /// # Safety
+    ///
+    /// This function should only be called with a valid, non-null choice iterator pointer from Tesseract.
+    /// `ChoiceIterator` will be responsible for freeing it.
+    /// The lifetime must not exceed that of the `TessResultIterator` it came from.
+    pub unsafe fn new(raw: *mut tesseract_sys::TessChoiceIterator) -> Self {
+        Self(raw, PhantomData)
+    }
+
+    /// Wrapper for [`TessChoiceIteratorNext`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a9efae00e9e27196ed84defd55e3421b7)
+    ///
+    /// Moves to the next choice for the symbol. Returns `false` if there are no more choices.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> bool {
+        unsafe { TessChoiceIteratorNext(self.0) != 0 }
+    }
+
+    /// Wrapper for [`TessChoiceIteratorGetUTF8Text`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a2837fe78f04ba3393c98685d98995ae3)
+    ///
+    /// Returns the text of the current choice, or `None` if there isn't one.
+    /// The string is owned by Tesseract, so doesn't need to be freed.
+    pub fn get_utf8_text(&self) -> Option<&CStr> {
+        let ptr = unsafe { TessChoiceIteratorGetUTF8Text(self.0) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(ptr) })
+        }
+    }
+
+    /// Wrapper for [`TessChoiceIteratorConfidence`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#aff4d73f8aafe92f4789aa3249907b4a1)
+    ///
+    /// Returns the confidence (between 0 and 100) of the current choice.
+    pub fn confidence(&self) -> c_float {
+        unsafe { TessChoiceIteratorConfidence(self.0) }
+    }
+}
+
+#[test]
+fn choice_iterator_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (mut tess, _pix) = crate::test_api()?;
+    tess.set_variable(
+        &std::ffi::CString::new("lstm_choice_mode")?,
+        &std::ffi::CString::new("2")?,
+    )?;
+    tess.recognize()?;
+    let mut result_iterator = tess.get_iterator().unwrap();
+    let mut choice_iterator = result_iterator.get_choice_iterator().unwrap();
+    let mut choices = Vec::new();
+    loop {
+        if let Some(text) = choice_iterator.get_utf8_text() {
+            choices.push(text.to_str()?.to_owned());
+            assert!(choice_iterator.confidence() >= 0.0);
+        }
+        if !choice_iterator.next() {
+            break;
+        }
+    }
+    assert!(choices.iter().any(|choice| choice == "t"));
+    drop(choice_iterator);
+
+    while result_iterator.next(crate::PageIteratorLevel::Symbol) {}
+    assert!(result_iterator.get_choice_iterator().is_none());
+    Ok(())
+}
@@ -1,16 +1,57 @@
+mod choice_iterator;
+mod monitor;
+mod ocr_engine_mode;
+mod orientation;
+mod page;
+mod page_iterator;
+mod page_iterator_level;
+mod page_seg_mode;
+mod paragraph_info;
+mod pdf_renderer;
+mod poly_block_type;
+mod result_iterator;
+mod result_renderer;
 mod tess_base_api;
+mod tess_base_api_builder;
 mod text;
 
 use self::tesseract_sys::TessVersion;
+pub use choice_iterator::ChoiceIterator;
 pub use leptonica_plumbing;
 pub use leptonica_plumbing::leptonica_sys;
+pub use monitor::Monitor;
+pub use ocr_engine_mode::{OcrEngineMode, OcrEngineModeFromRawError};
+pub use orientation::{
+    BlockOrientation, Orientation, OrientationFromRawError, TextlineOrder,
+    TextlineOrderFromRawError, WritingDirection, WritingDirectionFromRawError,
+};
+pub use page::{Baseline, Block, BoundingBox, Line, Page, Paragraph, Symbol, Word};
+pub use page_iterator::PageIterator;
+pub use page_iterator_level::{PageIteratorLevel, PageIteratorLevelFromRawError};
+pub use page_seg_mode::{PageSegMode, PageSegModeFromRawError};
+pub use paragraph_info::{
+    ParagraphInfo, ParagraphJustification, ParagraphJustificationFromRawError,
+};
+pub use pdf_renderer::PdfRenderer;
+pub use poly_block_type::{PolyBlockType, PolyBlockTypeFromRawError};
+pub use result_iterator::{ResultIterator, ResultIteratorGetUtf8TextError, WordAttributes};
+pub use result_renderer::{
+    ResultRenderer, ResultRendererAddImageError, ResultRendererBeginDocumentError,
+    ResultRendererEndDocumentError,
+};
 use std::ffi::CStr;
 pub use tess_base_api::{
-    TessBaseApi, TessBaseApiGetAltoTextError, TessBaseApiGetHocrTextError,
-    TessBaseApiGetLstmBoxTextError, TessBaseApiGetTsvTextError, TessBaseApiGetUtf8TextError,
-    TessBaseApiGetWordStrBoxTextError, TessBaseApiInitError, TessBaseApiRecogniseError,
-    TessBaseApiSetImageSafetyError, TessBaseApiSetVariableError,
+    ComponentImages, OrientationScript, TessBaseApi, TessBaseApiDetectOrientationScriptError,
+    TessBaseApiGetAltoTextError, TessBaseApiGetConnectedComponentsError,
+    TessBaseApiGetHocrTextError, TessBaseApiGetLstmBoxTextError, TessBaseApiGetOsdTextError,
+    TessBaseApiGetRegionsError, TessBaseApiGetStripsError, TessBaseApiGetTextlinesError,
+    TessBaseApiGetTsvTextError, TessBaseApiGetUtf8TextError, TessBaseApiGetVariablesError,
+    TessBaseApiGetWordStrBoxTextError, TessBaseApiGetWordsError, TessBaseApiInitError,
+    TessBaseApiPrintVariablesToFileError, TessBaseApiProcessPageError,
+    TessBaseApiProcessPagesError, TessBaseApiRecogniseError, TessBaseApiSetImageSafetyError,
+    TessBaseApiSetVariableError,
 };
+pub use tess_base_api_builder::{TessBaseApiBuildError, TessBaseApiBuilder};
 pub use tesseract_sys;
 pub use text::Text;
 
@@ -20,3 +61,19 @@ pub use text::Text;
 pub fn version() -> &'static CStr {
     unsafe { CStr::from_ptr(TessVersion()) }
 }
+
+/// Create a `TessBaseApi` initialized with the default language, with the bundled `image.png` set as its image.
+#[cfg(test)]
+fn test_api() -> Result<
+    (
+        TessBaseApi,
+        leptonica_plumbing::memory::RefCountedExclusive<leptonica_plumbing::Pix>,
+    ),
+    Box<dyn std::error::Error>,
+> {
+    let mut tess = TessBaseApi::create();
+    tess.init_2(None, None)?;
+    let pix = leptonica_plumbing::Pix::read_mem(include_bytes!("../image.png"))?;
+    tess.set_image_2(&pix);
+    Ok((tess, pix))
+}
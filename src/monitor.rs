@@ -0,0 +1,91 @@
+extern crate tesseract_sys;
+
+use self::tesseract_sys::{
+    TessMonitorCreate, TessMonitorDelete, TessMonitorGetProgress, TessMonitorSetCancelFunc,
+    TessMonitorSetCancelThis, TessMonitorSetDeadlineMSecs, ETEXT_DESC,
+};
+use std::os::raw::{c_int, c_void};
+use std::panic::{self, AssertUnwindSafe};
+
+type CancelFunc = dyn FnMut(c_int) -> bool + Send;
+
+/// Wrapper around [`ETEXT_DESC`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html)
+///
+/// Reports progress of, and allows cancelling, `TessBaseApi::recognize_with_monitor`.
+pub struct Monitor {
+    raw: *mut ETEXT_DESC,
+    cancel: Option<Box<Box<CancelFunc>>>,
+}
+
+unsafe impl Send for Monitor {}
+
+impl Drop for Monitor {
+    fn drop(&mut self) {
+        unsafe { TessMonitorDelete(self.raw) }
+    }
+}
+
+impl Default for Monitor {
+    fn default() -> Self {
+        Self::create()
+    }
+}
+
+impl std::fmt::Debug for Monitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Monitor")
+            .field("raw", &self.raw)
+            .field("cancel", &self.cancel.is_some())
+            .finish()
+    }
+}
+
+unsafe extern "C" fn cancel_trampoline(cancel_this: *mut c_void, words: c_int) -> bool {
+    let cancel = &mut *(cancel_this as *mut Box<CancelFunc>);
+    // Unwinding into Tesseract is undefined behaviour, so treat a panic as a request to cancel.
+    panic::catch_unwind(AssertUnwindSafe(|| cancel(words))).unwrap_or(true)
+}
+
+impl Monitor {
+    /// Wrapper for [`TessMonitorCreate`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a88fa50ff03aeb4cc308d3ac2f32960c7)
+    pub fn create() -> Self {
+        Self {
+            raw: unsafe { TessMonitorCreate() },
+            cancel: None,
+        }
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut ETEXT_DESC {
+        self.raw
+    }
+
+    /// Wrapper for [`TessMonitorGetProgress`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a9b789594595aae1ee4e167fe155669fa)
+    ///
+    /// Returns the percentage (between 0 and 100) of recognition completed.
+    pub fn get_progress(&self) -> c_int {
+        unsafe { TessMonitorGetProgress(self.raw) }
+    }
+
+    /// Wrapper for [`TessMonitorSetDeadlineMSecs`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a61bde0908bd60b01fef9f02396cfd94c)
+    ///
+    /// Stop recognition once `deadline` milliseconds have passed from now.
+    pub fn set_deadline_msecs(&mut self, deadline: c_int) {
+        unsafe { TessMonitorSetDeadlineMSecs(self.raw, deadline) }
+    }
+
+    /// Wrapper for [`TessMonitorSetCancelFunc`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a624fc80d937886924f074922d80e4b80) and [`TessMonitorSetCancelThis`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a598d619117cd804287056e5528073955)
+    ///
+    /// The callback is called periodically during recognition with the number of words recognized so far.
+    /// Return `true` to cancel recognition. Recognition is also cancelled if the callback panics.
+    pub fn set_cancel_func<F>(&mut self, cancel: F)
+    where
+        F: FnMut(c_int) -> bool + Send + 'static,
+    {
+        let mut cancel: Box<Box<CancelFunc>> = Box::new(Box::new(cancel));
+        unsafe {
+            TessMonitorSetCancelThis(self.raw, (&mut *cancel as *mut Box<CancelFunc>).cast());
+            TessMonitorSetCancelFunc(self.raw, Some(cancel_trampoline));
+        }
+        self.cancel = Some(cancel);
+    }
+}
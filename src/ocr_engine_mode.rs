@@ -0,0 +1,83 @@
+extern crate tesseract_sys;
+extern crate thiserror;
+
+use self::tesseract_sys::{
+    TessOcrEngineMode, TessOcrEngineMode_OEM_DEFAULT, TessOcrEngineMode_OEM_LSTM_ONLY,
+    TessOcrEngineMode_OEM_TESSERACT_LSTM_COMBINED, TessOcrEngineMode_OEM_TESSERACT_ONLY,
+};
+use self::thiserror::Error;
+use std::convert::TryFrom;
+
+/// Rust version of [`TessOcrEngineMode`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html)
+///
+/// Which recognition engine Tesseract runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OcrEngineMode {
+    /// Run the legacy Tesseract engine only.
+    TesseractOnly,
+    /// Run the LSTM neural network engine only.
+    LstmOnly,
+    /// Run both engines and combine their results.
+    TesseractLstmCombined,
+    /// Pick an engine based on the variables and the available traineddata.
+    #[default]
+    Default,
+}
+
+#[derive(Debug, Error)]
+#[error("{0} is not a valid TessOcrEngineMode")]
+pub struct OcrEngineModeFromRawError(pub TessOcrEngineMode);
+
+impl OcrEngineMode {
+    /// Every `OcrEngineMode`, in the order Tesseract declares them.
+    pub const ALL: [OcrEngineMode; 4] = [
+        OcrEngineMode::TesseractOnly,
+        OcrEngineMode::LstmOnly,
+        OcrEngineMode::TesseractLstmCombined,
+        OcrEngineMode::Default,
+    ];
+}
+
+impl From<OcrEngineMode> for TessOcrEngineMode {
+    fn from(mode: OcrEngineMode) -> Self {
+        match mode {
+            OcrEngineMode::TesseractOnly => TessOcrEngineMode_OEM_TESSERACT_ONLY,
+            OcrEngineMode::LstmOnly => TessOcrEngineMode_OEM_LSTM_ONLY,
+            OcrEngineMode::TesseractLstmCombined => TessOcrEngineMode_OEM_TESSERACT_LSTM_COMBINED,
+            OcrEngineMode::Default => TessOcrEngineMode_OEM_DEFAULT,
+        }
+    }
+}
+
+impl TryFrom<TessOcrEngineMode> for OcrEngineMode {
+    type Error = OcrEngineModeFromRawError;
+
+    #[allow(non_upper_case_globals)]
+    fn try_from(raw: TessOcrEngineMode) -> Result<Self, Self::Error> {
+        match raw {
+            TessOcrEngineMode_OEM_TESSERACT_ONLY => Ok(OcrEngineMode::TesseractOnly),
+            TessOcrEngineMode_OEM_LSTM_ONLY => Ok(OcrEngineMode::LstmOnly),
+            TessOcrEngineMode_OEM_TESSERACT_LSTM_COMBINED => {
+                Ok(OcrEngineMode::TesseractLstmCombined)
+            }
+            TessOcrEngineMode_OEM_DEFAULT => Ok(OcrEngineMode::Default),
+            _ => Err(OcrEngineModeFromRawError(raw)),
+        }
+    }
+}
+
+#[test]
+fn ocr_engine_mode_conversion_test() {
+    for mode in OcrEngineMode::ALL.iter().copied() {
+        assert_eq!(
+            OcrEngineMode::try_from(TessOcrEngineMode::from(mode)).ok(),
+            Some(mode)
+        );
+    }
+    assert_eq!(
+        TessOcrEngineMode::from(OcrEngineMode::LstmOnly),
+        tesseract_sys::TessOcrEngineMode_OEM_LSTM_ONLY
+    );
+    assert!(OcrEngineMode::try_from(4).is_err());
+}
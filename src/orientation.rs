@@ -0,0 +1,218 @@
+extern crate tesseract_sys;
+extern crate thiserror;
+
+use self::tesseract_sys::{
+    TessOrientation, TessOrientation_ORIENTATION_PAGE_DOWN, TessOrientation_ORIENTATION_PAGE_LEFT,
+    TessOrientation_ORIENTATION_PAGE_RIGHT, TessOrientation_ORIENTATION_PAGE_UP, TessTextlineOrder,
+    TessTextlineOrder_TEXTLINE_ORDER_LEFT_TO_RIGHT, TessTextlineOrder_TEXTLINE_ORDER_RIGHT_TO_LEFT,
+    TessTextlineOrder_TEXTLINE_ORDER_TOP_TO_BOTTOM, TessWritingDirection,
+    TessWritingDirection_WRITING_DIRECTION_LEFT_TO_RIGHT,
+    TessWritingDirection_WRITING_DIRECTION_RIGHT_TO_LEFT,
+    TessWritingDirection_WRITING_DIRECTION_TOP_TO_BOTTOM,
+};
+use self::thiserror::Error;
+use std::convert::TryFrom;
+use std::os::raw::c_float;
+
+/// Rust version of [`TessOrientation`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html)
+///
+/// Which way the top of the text in a block is facing in the image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Orientation {
+    /// The text is upright.
+    PageUp,
+    /// The top of the text faces right, so the text is rotated 90 degrees clockwise.
+    PageRight,
+    /// The text is upside down.
+    PageDown,
+    /// The top of the text faces left, so the text is rotated 90 degrees anticlockwise.
+    PageLeft,
+}
+
+#[derive(Debug, Error)]
+#[error("{0} is not a valid TessOrientation")]
+pub struct OrientationFromRawError(pub TessOrientation);
+
+impl Orientation {
+    /// Every `Orientation`, in the order Tesseract declares them.
+    pub const ALL: [Orientation; 4] = [
+        Orientation::PageUp,
+        Orientation::PageRight,
+        Orientation::PageDown,
+        Orientation::PageLeft,
+    ];
+}
+
+impl From<Orientation> for TessOrientation {
+    fn from(value: Orientation) -> Self {
+        match value {
+            Orientation::PageUp => TessOrientation_ORIENTATION_PAGE_UP,
+            Orientation::PageRight => TessOrientation_ORIENTATION_PAGE_RIGHT,
+            Orientation::PageDown => TessOrientation_ORIENTATION_PAGE_DOWN,
+            Orientation::PageLeft => TessOrientation_ORIENTATION_PAGE_LEFT,
+        }
+    }
+}
+
+impl TryFrom<TessOrientation> for Orientation {
+    type Error = OrientationFromRawError;
+
+    #[allow(non_upper_case_globals)]
+    fn try_from(raw: TessOrientation) -> Result<Self, Self::Error> {
+        match raw {
+            TessOrientation_ORIENTATION_PAGE_UP => Ok(Orientation::PageUp),
+            TessOrientation_ORIENTATION_PAGE_RIGHT => Ok(Orientation::PageRight),
+            TessOrientation_ORIENTATION_PAGE_DOWN => Ok(Orientation::PageDown),
+            TessOrientation_ORIENTATION_PAGE_LEFT => Ok(Orientation::PageLeft),
+            _ => Err(OrientationFromRawError(raw)),
+        }
+    }
+}
+
+/// Rust version of [`TessWritingDirection`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html)
+///
+/// The direction characters are written within a line, once the block is upright.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WritingDirection {
+    /// Such as English.
+    LeftToRight,
+    /// Such as Arabic and Hebrew.
+    RightToLeft,
+    /// Such as vertical Chinese and Japanese.
+    TopToBottom,
+}
+
+#[derive(Debug, Error)]
+#[error("{0} is not a valid TessWritingDirection")]
+pub struct WritingDirectionFromRawError(pub TessWritingDirection);
+
+impl WritingDirection {
+    /// Every `WritingDirection`, in the order Tesseract declares them.
+    pub const ALL: [WritingDirection; 3] = [
+        WritingDirection::LeftToRight,
+        WritingDirection::RightToLeft,
+        WritingDirection::TopToBottom,
+    ];
+}
+
+impl From<WritingDirection> for TessWritingDirection {
+    fn from(value: WritingDirection) -> Self {
+        match value {
+            WritingDirection::LeftToRight => TessWritingDirection_WRITING_DIRECTION_LEFT_TO_RIGHT,
+            WritingDirection::RightToLeft => TessWritingDirection_WRITING_DIRECTION_RIGHT_TO_LEFT,
+            WritingDirection::TopToBottom => TessWritingDirection_WRITING_DIRECTION_TOP_TO_BOTTOM,
+        }
+    }
+}
+
+impl TryFrom<TessWritingDirection> for WritingDirection {
+    type Error = WritingDirectionFromRawError;
+
+    #[allow(non_upper_case_globals)]
+    fn try_from(raw: TessWritingDirection) -> Result<Self, Self::Error> {
+        match raw {
+            TessWritingDirection_WRITING_DIRECTION_LEFT_TO_RIGHT => {
+                Ok(WritingDirection::LeftToRight)
+            }
+            TessWritingDirection_WRITING_DIRECTION_RIGHT_TO_LEFT => {
+                Ok(WritingDirection::RightToLeft)
+            }
+            TessWritingDirection_WRITING_DIRECTION_TOP_TO_BOTTOM => {
+                Ok(WritingDirection::TopToBottom)
+            }
+            _ => Err(WritingDirectionFromRawError(raw)),
+        }
+    }
+}
+
+/// Rust version of [`TessTextlineOrder`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html)
+///
+/// The order lines follow each other within a block, once the block is upright.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextlineOrder {
+    /// Lines follow each other from left to right, as with vertical Mongolian.
+    LeftToRight,
+    /// Lines follow each other from right to left, as with vertical Chinese and Japanese.
+    RightToLeft,
+    /// Lines follow each other from top to bottom, as with most horizontal scripts.
+    TopToBottom,
+}
+
+#[derive(Debug, Error)]
+#[error("{0} is not a valid TessTextlineOrder")]
+pub struct TextlineOrderFromRawError(pub TessTextlineOrder);
+
+impl TextlineOrder {
+    /// Every `TextlineOrder`, in the order Tesseract declares them.
+    pub const ALL: [TextlineOrder; 3] = [
+        TextlineOrder::LeftToRight,
+        TextlineOrder::RightToLeft,
+        TextlineOrder::TopToBottom,
+    ];
+}
+
+impl From<TextlineOrder> for TessTextlineOrder {
+    fn from(value: TextlineOrder) -> Self {
+        match value {
+            TextlineOrder::LeftToRight => TessTextlineOrder_TEXTLINE_ORDER_LEFT_TO_RIGHT,
+            TextlineOrder::RightToLeft => TessTextlineOrder_TEXTLINE_ORDER_RIGHT_TO_LEFT,
+            TextlineOrder::TopToBottom => TessTextlineOrder_TEXTLINE_ORDER_TOP_TO_BOTTOM,
+        }
+    }
+}
+
+impl TryFrom<TessTextlineOrder> for TextlineOrder {
+    type Error = TextlineOrderFromRawError;
+
+    #[allow(non_upper_case_globals)]
+    fn try_from(raw: TessTextlineOrder) -> Result<Self, Self::Error> {
+        match raw {
+            TessTextlineOrder_TEXTLINE_ORDER_LEFT_TO_RIGHT => Ok(TextlineOrder::LeftToRight),
+            TessTextlineOrder_TEXTLINE_ORDER_RIGHT_TO_LEFT => Ok(TextlineOrder::RightToLeft),
+            TessTextlineOrder_TEXTLINE_ORDER_TOP_TO_BOTTOM => Ok(TextlineOrder::TopToBottom),
+            _ => Err(TextlineOrderFromRawError(raw)),
+        }
+    }
+}
+
+/// The layout of the current block, returned by `PageIterator::orientation`.
+///
+/// To read the block in order, rotate it upright according to `orientation`,
+/// then read lines in `textline_order` and characters within each line in `writing_direction`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockOrientation {
+    pub orientation: Orientation,
+    pub writing_direction: WritingDirection,
+    pub textline_order: TextlineOrder,
+    /// The angle in radians the block must be rotated by, after `orientation`, to make its lines horizontal.
+    pub deskew_angle: c_float,
+}
+
+#[test]
+fn orientation_conversion_test() {
+    for orientation in Orientation::ALL.iter().copied() {
+        assert_eq!(
+            Orientation::try_from(TessOrientation::from(orientation)).ok(),
+            Some(orientation)
+        );
+    }
+    for direction in WritingDirection::ALL.iter().copied() {
+        assert_eq!(
+            WritingDirection::try_from(TessWritingDirection::from(direction)).ok(),
+            Some(direction)
+        );
+    }
+    for order in TextlineOrder::ALL.iter().copied() {
+        assert_eq!(
+            TextlineOrder::try_from(TessTextlineOrder::from(order)).ok(),
+            Some(order)
+        );
+    }
+    assert!(Orientation::try_from(4).is_err());
+    assert!(WritingDirection::try_from(3).is_err());
+    assert!(TextlineOrder::try_from(3).is_err());
+}
@@ -0,0 +1,237 @@
+use crate::{
+    BlockOrientation, PageIteratorLevel, ParagraphInfo, PolyBlockType, ResultIterator,
+    WordAttributes,
+};
+use std::os::raw::{c_float, c_int};
+
+/// A rectangle in image pixel coordinates. `right` and `bottom` are exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoundingBox {
+    pub left: c_int,
+    pub top: c_int,
+    pub right: c_int,
+    pub bottom: c_int,
+}
+
+impl From<(c_int, c_int, c_int, c_int)> for BoundingBox {
+    fn from((left, top, right, bottom): (c_int, c_int, c_int, c_int)) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+}
+
+/// A line from `(x1, y1)` to `(x2, y2)` in image pixel coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Baseline {
+    pub x1: c_int,
+    pub y1: c_int,
+    pub x2: c_int,
+    pub y2: c_int,
+}
+
+impl From<(c_int, c_int, c_int, c_int)> for Baseline {
+    fn from((x1, y1, x2, y2): (c_int, c_int, c_int, c_int)) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+}
+
+/// The recognition results of a page, returned by `TessBaseApi::recognize_to_page`.
+///
+/// Each level holds the bounding box, confidence (between 0 and 100) and text of its element, in reading order.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Page {
+    pub blocks: Vec<Block>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Block {
+    pub bounding_box: BoundingBox,
+    pub confidence: c_float,
+    pub text: String,
+    pub block_type: PolyBlockType,
+    pub orientation: Option<BlockOrientation>,
+    pub paragraphs: Vec<Paragraph>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Paragraph {
+    pub bounding_box: BoundingBox,
+    pub confidence: c_float,
+    pub text: String,
+    /// `None` if Tesseract has no paragraph model for this paragraph.
+    pub info: Option<ParagraphInfo>,
+    pub lines: Vec<Line>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line {
+    pub bounding_box: BoundingBox,
+    pub confidence: c_float,
+    pub text: String,
+    pub baseline: Option<Baseline>,
+    pub words: Vec<Word>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Word {
+    pub bounding_box: BoundingBox,
+    pub confidence: c_float,
+    pub text: String,
+    pub attributes: WordAttributes,
+    pub symbols: Vec<Symbol>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Symbol {
+    pub bounding_box: BoundingBox,
+    pub confidence: c_float,
+    pub text: String,
+    pub is_superscript: bool,
+    pub is_subscript: bool,
+    pub is_dropcap: bool,
+}
+
+fn text(iterator: &ResultIterator, level: PageIteratorLevel) -> String {
+    iterator
+        .get_utf8_text(level)
+        .map(|text| text.as_ref().to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+fn bounding_box(iterator: &ResultIterator, level: PageIteratorLevel) -> BoundingBox {
+    iterator.bounding_box(level).unwrap_or_default().into()
+}
+
+impl Page {
+    /// Collect the results from `iterator`'s current position to the end of the page, moving it one symbol at a time.
+    pub fn from_iterator(iterator: &mut ResultIterator) -> Self {
+        let mut page = Page::default();
+        // An iterator over an empty page has no current element.
+        if iterator.bounding_box(PageIteratorLevel::Symbol).is_none() {
+            return page;
+        }
+        loop {
+            if iterator.is_at_beginning_of(PageIteratorLevel::Block) || page.blocks.is_empty() {
+                let page_iterator = iterator.get_page_iterator();
+                page.blocks.push(Block {
+                    bounding_box: bounding_box(iterator, PageIteratorLevel::Block),
+                    confidence: iterator.confidence(PageIteratorLevel::Block),
+                    text: text(iterator, PageIteratorLevel::Block),
+                    block_type: page_iterator
+                        .poly_block_type()
+                        .unwrap_or(PolyBlockType::Unknown),
+                    orientation: page_iterator.orientation(),
+                    paragraphs: Vec::new(),
+                });
+            }
+            let block = page.blocks.last_mut().unwrap();
+
+            if iterator.is_at_beginning_of(PageIteratorLevel::Para) || block.paragraphs.is_empty() {
+                block.paragraphs.push(Paragraph {
+                    bounding_box: bounding_box(iterator, PageIteratorLevel::Para),
+                    confidence: iterator.confidence(PageIteratorLevel::Para),
+                    text: text(iterator, PageIteratorLevel::Para),
+                    info: iterator.get_page_iterator().paragraph_info(),
+                    lines: Vec::new(),
+                });
+            }
+            let paragraph = block.paragraphs.last_mut().unwrap();
+
+            if iterator.is_at_beginning_of(PageIteratorLevel::TextLine)
+                || paragraph.lines.is_empty()
+            {
+                paragraph.lines.push(Line {
+                    bounding_box: bounding_box(iterator, PageIteratorLevel::TextLine),
+                    confidence: iterator.confidence(PageIteratorLevel::TextLine),
+                    text: text(iterator, PageIteratorLevel::TextLine),
+                    baseline: iterator
+                        .get_page_iterator()
+                        .baseline(PageIteratorLevel::TextLine)
+                        .map(Baseline::from),
+                    words: Vec::new(),
+                });
+            }
+            let line = paragraph.lines.last_mut().unwrap();
+
+            if iterator.is_at_beginning_of(PageIteratorLevel::Word) || line.words.is_empty() {
+                line.words.push(Word {
+                    bounding_box: bounding_box(iterator, PageIteratorLevel::Word),
+                    confidence: iterator.confidence(PageIteratorLevel::Word),
+                    text: text(iterator, PageIteratorLevel::Word),
+                    attributes: iterator.word_attributes(),
+                    symbols: Vec::new(),
+                });
+            }
+            let word = line.words.last_mut().unwrap();
+
+            word.symbols.push(Symbol {
+                bounding_box: bounding_box(iterator, PageIteratorLevel::Symbol),
+                confidence: iterator.confidence(PageIteratorLevel::Symbol),
+                text: text(iterator, PageIteratorLevel::Symbol),
+                is_superscript: iterator.symbol_is_superscript(),
+                is_subscript: iterator.symbol_is_subscript(),
+                is_dropcap: iterator.symbol_is_dropcap(),
+            });
+
+            if !iterator.next(PageIteratorLevel::Symbol) {
+                break;
+            }
+        }
+        page
+    }
+}
+
+#[test]
+fn recognize_to_page_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (mut tess, _pix) = crate::test_api()?;
+    let page = tess.recognize_to_page()?;
+
+    assert_eq!(page.blocks.len(), 1);
+    assert!(matches!(
+        page.blocks[0].block_type,
+        PolyBlockType::FlowingText | PolyBlockType::HeadingText | PolyBlockType::PulloutText
+    ));
+    let paragraph = &page.blocks[0].paragraphs[0];
+    let line = &paragraph.lines[0];
+    assert_eq!(line.words.len(), 1);
+    let word = &line.words[0];
+    assert_eq!(word.text, "tesseract_plumbing");
+    assert!(word.confidence > 0.0);
+    assert_eq!(word.attributes.language.as_deref(), Some("eng"));
+    let symbols: String = word
+        .symbols
+        .iter()
+        .map(|symbol| symbol.text.as_str())
+        .collect();
+    assert_eq!(symbols, word.text);
+    assert!(word
+        .symbols
+        .iter()
+        .all(|symbol| !symbol.is_superscript && !symbol.is_subscript && !symbol.is_dropcap));
+    assert!(word.bounding_box.left < word.bounding_box.right);
+    assert!(line.bounding_box.left <= word.bounding_box.left);
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn page_serde_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (mut tess, _pix) = crate::test_api()?;
+    let page = tess.recognize_to_page()?;
+    let json = serde_json::to_string(&page)?;
+    assert!(json.contains("\"text\":\"tesseract_plumbing\""));
+    assert_eq!(serde_json::from_str::<Page>(&json)?, page);
+    Ok(())
+}
@@ -0,0 +1,297 @@
+extern crate tesseract_sys;
+
+use self::tesseract_sys::{
+    TessOrientation_ORIENTATION_PAGE_UP, TessPageIteratorBaseline, TessPageIteratorBegin,
+    TessPageIteratorBlockType, TessPageIteratorBoundingBox, TessPageIteratorDelete,
+    TessPageIteratorGetBinaryImage, TessPageIteratorGetImage, TessPageIteratorIsAtBeginningOf,
+    TessPageIteratorIsAtFinalElement, TessPageIteratorNext, TessPageIteratorOrientation,
+    TessPageIteratorParagraphInfo, TessParagraphJustification_JUSTIFICATION_UNKNOWN,
+    TessPolyBlockType, TessTextlineOrder_TEXTLINE_ORDER_TOP_TO_BOTTOM,
+    TessWritingDirection_WRITING_DIRECTION_LEFT_TO_RIGHT,
+};
+use crate::{
+    BlockOrientation, Orientation, PageIteratorLevel, ParagraphInfo, ParagraphJustification,
+    PolyBlockType, PolyBlockTypeFromRawError, TextlineOrder, WritingDirection,
+};
+use leptonica_plumbing::memory::RefCountedExclusive;
+use leptonica_plumbing::Pix;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+use std::os::raw::c_int;
+
+/// Wrapper around [`TessPageIterator`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html)
+///
+/// Borrows the `TessBaseApi` that produced it, so it can't outlive the layout analysis.
+#[derive(Debug)]
+pub struct PageIterator<'a>(
+    *mut tesseract_sys::TessPageIterator,
+    PhantomData<&'a mut tesseract_sys::TessBaseAPI>,
+);
+
+unsafe impl Send for PageIterator<'_> {}
+
+impl Drop for PageIterator<'_> {
+    fn drop(&mut self) {
+        unsafe { TessPageIteratorDelete(self.0) }
+    }
+}
+
+impl<'a> PageIterator<'a> {
+    /// # Safety
+    ///
+    /// This function should only be called with a valid, non-null page iterator pointer from Tesseract.
+    /// `PageIterator` will be responsible for freeing it.
+    /// The lifetime must not exceed that of the `TessBaseAPI` it came from.
+    pub unsafe fn new(raw: *mut tesseract_sys::TessPageIterator) -> Self {
+        Self(raw, PhantomData)
+    }
+
+    /// Wrapper for [`TessPageIteratorBegin`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a361165f7cc66e77193818a8894b261c9)
+    ///
+    /// Moves the iterator back to the start of the page.
+    pub fn begin(&mut self) {
+        unsafe { TessPageIteratorBegin(self.0) }
+    }
+
+    /// Wrapper for [`TessPageIteratorNext`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a88c83b37b60307e7301e3b20919db2b1)
+    ///
+    /// Moves to the start of the next object at the given level. Returns `false` if the end of the page was reached.
+    pub fn next(&mut self, level: PageIteratorLevel) -> bool {
+        unsafe { TessPageIteratorNext(self.0, level.into()) != 0 }
+    }
+
+    /// Wrapper for [`TessPageIteratorIsAtBeginningOf`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#ae1408d62c04b60e4aab18a935bb1a46f)
+    ///
+    /// Returns `true` if the iterator is at the start of an object at the given level.
+    pub fn is_at_beginning_of(&self, level: PageIteratorLevel) -> bool {
+        unsafe { TessPageIteratorIsAtBeginningOf(self.0, level.into()) != 0 }
+    }
+
+    /// Wrapper for [`TessPageIteratorIsAtFinalElement`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a01121c7ede2a3ee0bda72dc4dc4106d7)
+    ///
+    /// Returns `true` if the iterator is positioned at the last `element` within the current object at the given `level`.
+    pub fn is_at_final_element(
+        &self,
+        level: PageIteratorLevel,
+        element: PageIteratorLevel,
+    ) -> bool {
+        unsafe { TessPageIteratorIsAtFinalElement(self.0, level.into(), element.into()) != 0 }
+    }
+
+    /// Wrapper for [`TessPageIteratorBoundingBox`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a419724c02486a038a1c185417fc9c277)
+    ///
+    /// Returns the bounding rectangle of the current object at the given level as `(left, top, right, bottom)` in image pixel coordinates.
+    ///
+    /// Returns `None` if there is no such object at the current position.
+    pub fn bounding_box(&self, level: PageIteratorLevel) -> Option<(c_int, c_int, c_int, c_int)> {
+        let (mut left, mut top, mut right, mut bottom) = (0, 0, 0, 0);
+        let ret = unsafe {
+            TessPageIteratorBoundingBox(
+                self.0,
+                level.into(),
+                &mut left,
+                &mut top,
+                &mut right,
+                &mut bottom,
+            )
+        };
+        if ret == 0 {
+            None
+        } else {
+            Some((left, top, right, bottom))
+        }
+    }
+
+    /// Wrapper for [`TessPageIteratorGetBinaryImage`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#aa5a0fe5abfa55f4aebe0017bd90e950b)
+    ///
+    /// Returns the current object at the given level cropped from the thresholded image, as a 1 bit image.
+    ///
+    /// Returns `None` if there is no such object at the current position.
+    pub fn get_binary_image(&self, level: PageIteratorLevel) -> Option<RefCountedExclusive<Pix>> {
+        let ptr = unsafe { TessPageIteratorGetBinaryImage(self.0, level.into()) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCountedExclusive::new(Pix::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`TessPageIteratorGetImage`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a25f98e902238f4b6a47c280355f9843f)
+    ///
+    /// Returns the current object at the given level cropped from `original_image`, with `padding` pixels around it,
+    /// along with the `(left, top)` position of the crop in `original_image`.
+    /// `original_image` should be the image passed to `TessBaseApi::set_image_2`.
+    ///
+    /// Returns `None` if there is no such object at the current position.
+    pub fn get_image(
+        &self,
+        level: PageIteratorLevel,
+        padding: c_int,
+        original_image: &Pix,
+    ) -> Option<(RefCountedExclusive<Pix>, c_int, c_int)> {
+        let (mut left, mut top) = (0, 0);
+        let ptr = unsafe {
+            TessPageIteratorGetImage(
+                self.0,
+                level.into(),
+                padding,
+                *original_image.as_ref(),
+                &mut left,
+                &mut top,
+            )
+        };
+        if ptr.is_null() {
+            None
+        } else {
+            Some((
+                unsafe { RefCountedExclusive::new(Pix::new_from_pointer(ptr)) },
+                left,
+                top,
+            ))
+        }
+    }
+
+    /// Wrapper for [`TessPageIteratorBlockType`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a93e32d0519ae131e6be8ccc0a06d1185)
+    ///
+    /// Returns the type of the current block.
+    pub fn block_type(&self) -> TessPolyBlockType {
+        unsafe { TessPageIteratorBlockType(self.0) }
+    }
+
+    /// Wrapper for [`TessPageIteratorBlockType`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a93e32d0519ae131e6be8ccc0a06d1185)
+    ///
+    /// Returns the type of the current block as a `PolyBlockType`.
+    pub fn poly_block_type(&self) -> Result<PolyBlockType, PolyBlockTypeFromRawError> {
+        PolyBlockType::try_from(self.block_type())
+    }
+
+    /// Wrapper for [`TessPageIteratorBaseline`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#ab887fef65b1dc99d26a076b452e38a26)
+    ///
+    /// Returns the baseline of the current object at the given level as `(x1, y1, x2, y2)`.
+    ///
+    /// Returns `None` if there is no baseline at the current position.
+    pub fn baseline(&self, level: PageIteratorLevel) -> Option<(c_int, c_int, c_int, c_int)> {
+        let (mut x1, mut y1, mut x2, mut y2) = (0, 0, 0, 0);
+        let ret = unsafe {
+            TessPageIteratorBaseline(self.0, level.into(), &mut x1, &mut y1, &mut x2, &mut y2)
+        };
+        if ret == 0 {
+            None
+        } else {
+            Some((x1, y1, x2, y2))
+        }
+    }
+
+    /// Wrapper for [`TessPageIteratorOrientation`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a7c2d23a601f17b92f6c2eb130bbf4917)
+    ///
+    /// Returns the orientation, writing direction, textline order and deskew angle of the current block.
+    ///
+    /// Returns `None` if the iterator has passed the last block.
+    pub fn orientation(&self) -> Option<BlockOrientation> {
+        // Tesseract dereferences the current block without checking whether there is one.
+        self.bounding_box(PageIteratorLevel::Block)?;
+        let mut orientation = TessOrientation_ORIENTATION_PAGE_UP;
+        let mut writing_direction = TessWritingDirection_WRITING_DIRECTION_LEFT_TO_RIGHT;
+        let mut textline_order = TessTextlineOrder_TEXTLINE_ORDER_TOP_TO_BOTTOM;
+        let mut deskew_angle = 0.0;
+        unsafe {
+            TessPageIteratorOrientation(
+                self.0,
+                &mut orientation,
+                &mut writing_direction,
+                &mut textline_order,
+                &mut deskew_angle,
+            )
+        };
+        Some(BlockOrientation {
+            orientation: Orientation::try_from(orientation).ok()?,
+            writing_direction: WritingDirection::try_from(writing_direction).ok()?,
+            textline_order: TextlineOrder::try_from(textline_order).ok()?,
+            deskew_angle,
+        })
+    }
+
+    /// Wrapper for [`TessPageIteratorParagraphInfo`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#afcc6f39017ff1db35e060eb81b4ca40f)
+    ///
+    /// Returns the justification, list item and crown flags, and first line indent of the paragraph containing the current line.
+    ///
+    /// Returns `None` if Tesseract has no paragraph model for the current line.
+    pub fn paragraph_info(&self) -> Option<ParagraphInfo> {
+        let mut justification = TessParagraphJustification_JUSTIFICATION_UNKNOWN;
+        let mut is_list_item = 0;
+        let mut is_crown = 0;
+        let mut first_line_indent = 0;
+        unsafe {
+            TessPageIteratorParagraphInfo(
+                self.0,
+                &mut justification,
+                &mut is_list_item,
+                &mut is_crown,
+                &mut first_line_indent,
+            )
+        };
+        match ParagraphJustification::try_from(justification).ok()? {
+            // Tesseract leaves the other fields uninitialised when there is no paragraph model.
+            ParagraphJustification::Unknown => None,
+            justification => Some(ParagraphInfo {
+                justification,
+                is_list_item: is_list_item != 0,
+                is_crown: is_crown != 0,
+                first_line_indent,
+            }),
+        }
+    }
+}
+
+#[test]
+fn page_iterator_from_result_iterator_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (mut tess, _pix) = crate::test_api()?;
+    tess.recognize()?;
+    let result_iterator = tess.get_iterator().unwrap();
+    let mut page_iterator = result_iterator.get_page_iterator();
+    drop(result_iterator);
+
+    assert!(page_iterator.is_at_beginning_of(PageIteratorLevel::Block));
+    assert!(page_iterator.is_at_final_element(PageIteratorLevel::Block, PageIteratorLevel::Word));
+    assert!(page_iterator
+        .bounding_box(PageIteratorLevel::Word)
+        .is_some());
+    assert!(page_iterator.baseline(PageIteratorLevel::Word).is_some());
+    assert!(page_iterator.next(PageIteratorLevel::Symbol));
+    assert!(!page_iterator.is_at_beginning_of(PageIteratorLevel::Word));
+    page_iterator.begin();
+    assert!(page_iterator.is_at_beginning_of(PageIteratorLevel::Word));
+    let orientation = page_iterator.orientation().unwrap();
+    assert_eq!(orientation.orientation, Orientation::PageUp);
+    assert_eq!(orientation.writing_direction, WritingDirection::LeftToRight);
+    assert_eq!(orientation.textline_order, TextlineOrder::TopToBottom);
+    if let Some(paragraph_info) = page_iterator.paragraph_info() {
+        assert!(!paragraph_info.is_list_item);
+    }
+    while page_iterator.next(PageIteratorLevel::Block) {}
+    assert!(page_iterator.orientation().is_none());
+    Ok(())
+}
+
+#[test]
+fn page_iterator_image_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (mut tess, pix) = crate::test_api()?;
+    tess.recognize()?;
+    let page_iterator = tess.get_iterator().unwrap().get_page_iterator();
+    let (left, top, right, bottom) = page_iterator.bounding_box(PageIteratorLevel::Word).unwrap();
+
+    let binary = page_iterator
+        .get_binary_image(PageIteratorLevel::Word)
+        .unwrap();
+    assert_eq!(binary.get_depth(), 1);
+    assert_eq!(binary.get_width(), right - left);
+    assert_eq!(binary.get_height(), bottom - top);
+
+    let (image, image_left, image_top) = page_iterator
+        .get_image(PageIteratorLevel::Word, 1, &pix)
+        .unwrap();
+    assert_eq!(image.get_depth(), pix.get_depth());
+    assert!(image_left <= left && image_top <= top);
+    assert!(image.get_width() >= right - left);
+    Ok(())
+}
@@ -0,0 +1,86 @@
+extern crate tesseract_sys;
+extern crate thiserror;
+
+use self::tesseract_sys::{
+    TessPageIteratorLevel, TessPageIteratorLevel_RIL_BLOCK, TessPageIteratorLevel_RIL_PARA,
+    TessPageIteratorLevel_RIL_SYMBOL, TessPageIteratorLevel_RIL_TEXTLINE,
+    TessPageIteratorLevel_RIL_WORD,
+};
+use self::thiserror::Error;
+use std::convert::TryFrom;
+
+/// Rust version of [`TessPageIteratorLevel`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html)
+///
+/// The granularity an iterator moves at or reports on, from the coarsest to the finest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PageIteratorLevel {
+    /// A block of text, an image or a separator line.
+    Block,
+    /// A paragraph within a block.
+    Para,
+    /// A line within a paragraph.
+    TextLine,
+    /// A word within a line.
+    Word,
+    /// A symbol (character) within a word.
+    Symbol,
+}
+
+#[derive(Debug, Error)]
+#[error("{0} is not a valid TessPageIteratorLevel")]
+pub struct PageIteratorLevelFromRawError(pub TessPageIteratorLevel);
+
+impl PageIteratorLevel {
+    /// Every `PageIteratorLevel`, in the order Tesseract declares them.
+    pub const ALL: [PageIteratorLevel; 5] = [
+        PageIteratorLevel::Block,
+        PageIteratorLevel::Para,
+        PageIteratorLevel::TextLine,
+        PageIteratorLevel::Word,
+        PageIteratorLevel::Symbol,
+    ];
+}
+
+impl From<PageIteratorLevel> for TessPageIteratorLevel {
+    fn from(level: PageIteratorLevel) -> Self {
+        match level {
+            PageIteratorLevel::Block => TessPageIteratorLevel_RIL_BLOCK,
+            PageIteratorLevel::Para => TessPageIteratorLevel_RIL_PARA,
+            PageIteratorLevel::TextLine => TessPageIteratorLevel_RIL_TEXTLINE,
+            PageIteratorLevel::Word => TessPageIteratorLevel_RIL_WORD,
+            PageIteratorLevel::Symbol => TessPageIteratorLevel_RIL_SYMBOL,
+        }
+    }
+}
+
+impl TryFrom<TessPageIteratorLevel> for PageIteratorLevel {
+    type Error = PageIteratorLevelFromRawError;
+
+    #[allow(non_upper_case_globals)]
+    fn try_from(raw: TessPageIteratorLevel) -> Result<Self, Self::Error> {
+        match raw {
+            TessPageIteratorLevel_RIL_BLOCK => Ok(PageIteratorLevel::Block),
+            TessPageIteratorLevel_RIL_PARA => Ok(PageIteratorLevel::Para),
+            TessPageIteratorLevel_RIL_TEXTLINE => Ok(PageIteratorLevel::TextLine),
+            TessPageIteratorLevel_RIL_WORD => Ok(PageIteratorLevel::Word),
+            TessPageIteratorLevel_RIL_SYMBOL => Ok(PageIteratorLevel::Symbol),
+            _ => Err(PageIteratorLevelFromRawError(raw)),
+        }
+    }
+}
+
+#[test]
+fn page_iterator_level_conversion_test() {
+    for level in PageIteratorLevel::ALL.iter().copied() {
+        assert_eq!(
+            PageIteratorLevel::try_from(TessPageIteratorLevel::from(level)).ok(),
+            Some(level)
+        );
+    }
+    assert_eq!(
+        TessPageIteratorLevel::from(PageIteratorLevel::TextLine),
+        tesseract_sys::TessPageIteratorLevel_RIL_TEXTLINE
+    );
+    assert!(PageIteratorLevel::try_from(5).is_err());
+}
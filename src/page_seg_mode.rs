@@ -0,0 +1,135 @@
+extern crate tesseract_sys;
+extern crate thiserror;
+
+use self::tesseract_sys::{
+    TessPageSegMode, TessPageSegMode_PSM_AUTO, TessPageSegMode_PSM_AUTO_ONLY,
+    TessPageSegMode_PSM_AUTO_OSD, TessPageSegMode_PSM_CIRCLE_WORD, TessPageSegMode_PSM_OSD_ONLY,
+    TessPageSegMode_PSM_RAW_LINE, TessPageSegMode_PSM_SINGLE_BLOCK,
+    TessPageSegMode_PSM_SINGLE_BLOCK_VERT_TEXT, TessPageSegMode_PSM_SINGLE_CHAR,
+    TessPageSegMode_PSM_SINGLE_COLUMN, TessPageSegMode_PSM_SINGLE_LINE,
+    TessPageSegMode_PSM_SINGLE_WORD, TessPageSegMode_PSM_SPARSE_TEXT,
+    TessPageSegMode_PSM_SPARSE_TEXT_OSD,
+};
+use self::thiserror::Error;
+use std::convert::TryFrom;
+
+/// Rust version of [`TessPageSegMode`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html)
+///
+/// How Tesseract splits the image into blocks, lines and words before recognizing them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PageSegMode {
+    /// Orientation and script detection only.
+    OsdOnly,
+    /// Automatic page segmentation with orientation and script detection.
+    AutoOsd,
+    /// Automatic page segmentation, but no OSD or OCR.
+    AutoOnly,
+    /// Fully automatic page segmentation, but no OSD.
+    Auto,
+    /// Assume a single column of text of variable sizes.
+    SingleColumn,
+    /// Assume a single uniform block of vertically aligned text.
+    SingleBlockVertText,
+    /// Assume a single uniform block of text.
+    SingleBlock,
+    /// Treat the image as a single text line.
+    SingleLine,
+    /// Treat the image as a single word.
+    SingleWord,
+    /// Treat the image as a single word in a circle.
+    CircleWord,
+    /// Treat the image as a single character.
+    SingleChar,
+    /// Find as much text as possible in no particular order.
+    SparseText,
+    /// Sparse text with orientation and script detection.
+    SparseTextOsd,
+    /// Treat the image as a single text line, bypassing Tesseract-specific hacks.
+    RawLine,
+}
+
+#[derive(Debug, Error)]
+#[error("{0} is not a valid TessPageSegMode")]
+pub struct PageSegModeFromRawError(pub TessPageSegMode);
+
+impl PageSegMode {
+    /// Every `PageSegMode`, in the order Tesseract declares them.
+    pub const ALL: [PageSegMode; 14] = [
+        PageSegMode::OsdOnly,
+        PageSegMode::AutoOsd,
+        PageSegMode::AutoOnly,
+        PageSegMode::Auto,
+        PageSegMode::SingleColumn,
+        PageSegMode::SingleBlockVertText,
+        PageSegMode::SingleBlock,
+        PageSegMode::SingleLine,
+        PageSegMode::SingleWord,
+        PageSegMode::CircleWord,
+        PageSegMode::SingleChar,
+        PageSegMode::SparseText,
+        PageSegMode::SparseTextOsd,
+        PageSegMode::RawLine,
+    ];
+}
+
+impl From<PageSegMode> for TessPageSegMode {
+    fn from(mode: PageSegMode) -> Self {
+        match mode {
+            PageSegMode::OsdOnly => TessPageSegMode_PSM_OSD_ONLY,
+            PageSegMode::AutoOsd => TessPageSegMode_PSM_AUTO_OSD,
+            PageSegMode::AutoOnly => TessPageSegMode_PSM_AUTO_ONLY,
+            PageSegMode::Auto => TessPageSegMode_PSM_AUTO,
+            PageSegMode::SingleColumn => TessPageSegMode_PSM_SINGLE_COLUMN,
+            PageSegMode::SingleBlockVertText => TessPageSegMode_PSM_SINGLE_BLOCK_VERT_TEXT,
+            PageSegMode::SingleBlock => TessPageSegMode_PSM_SINGLE_BLOCK,
+            PageSegMode::SingleLine => TessPageSegMode_PSM_SINGLE_LINE,
+            PageSegMode::SingleWord => TessPageSegMode_PSM_SINGLE_WORD,
+            PageSegMode::CircleWord => TessPageSegMode_PSM_CIRCLE_WORD,
+            PageSegMode::SingleChar => TessPageSegMode_PSM_SINGLE_CHAR,
+            PageSegMode::SparseText => TessPageSegMode_PSM_SPARSE_TEXT,
+            PageSegMode::SparseTextOsd => TessPageSegMode_PSM_SPARSE_TEXT_OSD,
+            PageSegMode::RawLine => TessPageSegMode_PSM_RAW_LINE,
+        }
+    }
+}
+
+impl TryFrom<TessPageSegMode> for PageSegMode {
+    type Error = PageSegModeFromRawError;
+
+    #[allow(non_upper_case_globals)]
+    fn try_from(raw: TessPageSegMode) -> Result<Self, Self::Error> {
+        match raw {
+            TessPageSegMode_PSM_OSD_ONLY => Ok(PageSegMode::OsdOnly),
+            TessPageSegMode_PSM_AUTO_OSD => Ok(PageSegMode::AutoOsd),
+            TessPageSegMode_PSM_AUTO_ONLY => Ok(PageSegMode::AutoOnly),
+            TessPageSegMode_PSM_AUTO => Ok(PageSegMode::Auto),
+            TessPageSegMode_PSM_SINGLE_COLUMN => Ok(PageSegMode::SingleColumn),
+            TessPageSegMode_PSM_SINGLE_BLOCK_VERT_TEXT => Ok(PageSegMode::SingleBlockVertText),
+            TessPageSegMode_PSM_SINGLE_BLOCK => Ok(PageSegMode::SingleBlock),
+            TessPageSegMode_PSM_SINGLE_LINE => Ok(PageSegMode::SingleLine),
+            TessPageSegMode_PSM_SINGLE_WORD => Ok(PageSegMode::SingleWord),
+            TessPageSegMode_PSM_CIRCLE_WORD => Ok(PageSegMode::CircleWord),
+            TessPageSegMode_PSM_SINGLE_CHAR => Ok(PageSegMode::SingleChar),
+            TessPageSegMode_PSM_SPARSE_TEXT => Ok(PageSegMode::SparseText),
+            TessPageSegMode_PSM_SPARSE_TEXT_OSD => Ok(PageSegMode::SparseTextOsd),
+            TessPageSegMode_PSM_RAW_LINE => Ok(PageSegMode::RawLine),
+            _ => Err(PageSegModeFromRawError(raw)),
+        }
+    }
+}
+
+#[test]
+fn page_seg_mode_conversion_test() {
+    for mode in PageSegMode::ALL.iter().copied() {
+        assert_eq!(
+            PageSegMode::try_from(TessPageSegMode::from(mode)).ok(),
+            Some(mode)
+        );
+    }
+    assert_eq!(
+        TessPageSegMode::from(PageSegMode::SingleLine),
+        tesseract_sys::TessPageSegMode_PSM_SINGLE_LINE
+    );
+    assert!(PageSegMode::try_from(tesseract_sys::TessPageSegMode_PSM_COUNT).is_err());
+}
@@ -0,0 +1,92 @@
+extern crate tesseract_sys;
+extern crate thiserror;
+
+use self::tesseract_sys::{
+    TessParagraphJustification, TessParagraphJustification_JUSTIFICATION_CENTER,
+    TessParagraphJustification_JUSTIFICATION_LEFT, TessParagraphJustification_JUSTIFICATION_RIGHT,
+    TessParagraphJustification_JUSTIFICATION_UNKNOWN,
+};
+use self::thiserror::Error;
+use std::convert::TryFrom;
+use std::os::raw::c_int;
+
+/// Rust version of [`TessParagraphJustification`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html)
+///
+/// How the lines of a paragraph are aligned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParagraphJustification {
+    /// The alignment couldn't be determined.
+    Unknown,
+    /// Lines are aligned to the start of the line (the right for right-to-left text).
+    Left,
+    /// Lines are centered.
+    Center,
+    /// Lines are aligned to the end of the line (the left for right-to-left text).
+    Right,
+}
+
+#[derive(Debug, Error)]
+#[error("{0} is not a valid TessParagraphJustification")]
+pub struct ParagraphJustificationFromRawError(pub TessParagraphJustification);
+
+impl ParagraphJustification {
+    /// Every `ParagraphJustification`, in the order Tesseract declares them.
+    pub const ALL: [ParagraphJustification; 4] = [
+        ParagraphJustification::Unknown,
+        ParagraphJustification::Left,
+        ParagraphJustification::Center,
+        ParagraphJustification::Right,
+    ];
+}
+
+impl From<ParagraphJustification> for TessParagraphJustification {
+    fn from(value: ParagraphJustification) -> Self {
+        match value {
+            ParagraphJustification::Unknown => TessParagraphJustification_JUSTIFICATION_UNKNOWN,
+            ParagraphJustification::Left => TessParagraphJustification_JUSTIFICATION_LEFT,
+            ParagraphJustification::Center => TessParagraphJustification_JUSTIFICATION_CENTER,
+            ParagraphJustification::Right => TessParagraphJustification_JUSTIFICATION_RIGHT,
+        }
+    }
+}
+
+impl TryFrom<TessParagraphJustification> for ParagraphJustification {
+    type Error = ParagraphJustificationFromRawError;
+
+    #[allow(non_upper_case_globals)]
+    fn try_from(raw: TessParagraphJustification) -> Result<Self, Self::Error> {
+        match raw {
+            TessParagraphJustification_JUSTIFICATION_UNKNOWN => Ok(ParagraphJustification::Unknown),
+            TessParagraphJustification_JUSTIFICATION_LEFT => Ok(ParagraphJustification::Left),
+            TessParagraphJustification_JUSTIFICATION_CENTER => Ok(ParagraphJustification::Center),
+            TessParagraphJustification_JUSTIFICATION_RIGHT => Ok(ParagraphJustification::Right),
+            _ => Err(ParagraphJustificationFromRawError(raw)),
+        }
+    }
+}
+
+/// The paragraph of the current line, returned by `PageIterator::paragraph_info`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParagraphInfo {
+    pub justification: ParagraphJustification,
+    /// Whether the paragraph is a list item, such as a bullet point.
+    pub is_list_item: bool,
+    /// Whether the first line of the paragraph is aligned with the other lines rather than indented,
+    /// as with the first paragraph after a heading.
+    pub is_crown: bool,
+    /// The indent of the first line relative to the other lines, in pixels.
+    pub first_line_indent: c_int,
+}
+
+#[test]
+fn paragraph_justification_conversion_test() {
+    for justification in ParagraphJustification::ALL.iter().copied() {
+        assert_eq!(
+            ParagraphJustification::try_from(TessParagraphJustification::from(justification)).ok(),
+            Some(justification)
+        );
+    }
+    assert!(ParagraphJustification::try_from(4).is_err());
+}
@@ -0,0 +1,91 @@
+extern crate tesseract_sys;
+
+use self::tesseract_sys::TessPDFRendererCreate;
+use crate::{
+    ResultRenderer, ResultRendererAddImageError, ResultRendererBeginDocumentError,
+    ResultRendererEndDocumentError, TessBaseApi,
+};
+use std::ffi::CStr;
+use std::os::raw::c_int;
+
+/// A `ResultRenderer` that writes a searchable PDF, with the recognized text as an invisible layer over each page image.
+///
+/// Feed it pages with `add_image` between `begin_document` and `end_document`.
+/// Convert it into a `ResultRenderer` to chain it with other renderers.
+#[derive(Debug)]
+pub struct PdfRenderer(ResultRenderer);
+
+impl PdfRenderer {
+    /// Wrapper for [`TessPDFRendererCreate`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a6ef7ea2a9fdee3258818dce52722c64a)
+    ///
+    /// Writes to `outputbase` with a `.pdf` extension, or to stdout if `outputbase` is `-`.
+    /// `datadir` is the directory containing `pdf.ttf`, usually `TessBaseApi::get_datapath`.
+    /// If `textonly` is true, the page images are left out so only the text layer is written.
+    pub fn create(outputbase: &CStr, datadir: &CStr, textonly: bool) -> Self {
+        Self(unsafe {
+            ResultRenderer::new(TessPDFRendererCreate(
+                outputbase.as_ptr(),
+                datadir.as_ptr(),
+                textonly as c_int,
+            ))
+        })
+    }
+
+    /// See `ResultRenderer::begin_document`.
+    pub fn begin_document(&mut self, title: &CStr) -> Result<(), ResultRendererBeginDocumentError> {
+        self.0.begin_document(title)
+    }
+
+    /// See `ResultRenderer::add_image`.
+    pub fn add_image(&mut self, api: &mut TessBaseApi) -> Result<(), ResultRendererAddImageError> {
+        self.0.add_image(api)
+    }
+
+    /// See `ResultRenderer::end_document`.
+    pub fn end_document(&mut self) -> Result<(), ResultRendererEndDocumentError> {
+        self.0.end_document()
+    }
+}
+
+impl From<PdfRenderer> for ResultRenderer {
+    fn from(renderer: PdfRenderer) -> Self {
+        renderer.0
+    }
+}
+
+#[test]
+fn pdf_renderer_test() -> Result<(), Box<dyn std::error::Error>> {
+    use std::ffi::CString;
+    use std::fs;
+
+    let (mut tess, _pix) = crate::test_api()?;
+    let datadir = tess.get_datapath().unwrap().to_owned();
+
+    let mut sizes = Vec::new();
+    for textonly in [false, true] {
+        let outputbase = std::env::temp_dir().join(format!(
+            "tesseract-plumbing-pdf-renderer-test-{}-{}",
+            std::process::id(),
+            textonly
+        ));
+        let mut renderer = PdfRenderer::create(
+            &CString::new(outputbase.to_str().unwrap())?,
+            &datadir,
+            textonly,
+        );
+        renderer.begin_document(&CString::new("title")?)?;
+        renderer.add_image(&mut tess)?;
+        renderer.end_document()?;
+        drop(renderer);
+
+        let pdf = fs::read(outputbase.with_extension("pdf"))?;
+        fs::remove_file(outputbase.with_extension("pdf"))?;
+        assert!(pdf.starts_with(b"%PDF"));
+        sizes.push(pdf.len());
+    }
+    assert!(sizes[1] < sizes[0]);
+
+    let renderer = ResultRenderer::from(PdfRenderer::create(&CString::new("-")?, &datadir, false));
+    assert_eq!(renderer.get_extension().to_str()?, "pdf");
+    Ok(())
+}
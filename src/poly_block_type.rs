@@ -0,0 +1,140 @@
+extern crate tesseract_sys;
+extern crate thiserror;
+
+use self::tesseract_sys::{
+    TessPolyBlockType, TessPolyBlockType_PT_CAPTION_TEXT, TessPolyBlockType_PT_EQUATION,
+    TessPolyBlockType_PT_FLOWING_IMAGE, TessPolyBlockType_PT_FLOWING_TEXT,
+    TessPolyBlockType_PT_HEADING_IMAGE, TessPolyBlockType_PT_HEADING_TEXT,
+    TessPolyBlockType_PT_HORZ_LINE, TessPolyBlockType_PT_INLINE_EQUATION,
+    TessPolyBlockType_PT_NOISE, TessPolyBlockType_PT_PULLOUT_IMAGE,
+    TessPolyBlockType_PT_PULLOUT_TEXT, TessPolyBlockType_PT_TABLE, TessPolyBlockType_PT_UNKNOWN,
+    TessPolyBlockType_PT_VERTICAL_TEXT, TessPolyBlockType_PT_VERT_LINE,
+};
+use self::thiserror::Error;
+use std::convert::TryFrom;
+
+/// Rust version of [`TessPolyBlockType`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html)
+///
+/// What a block found by layout analysis contains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PolyBlockType {
+    /// The type couldn't be determined.
+    Unknown,
+    /// Text that lives inside a column.
+    FlowingText,
+    /// Text that spans more than one column.
+    HeadingText,
+    /// Text that is in a cross-column pull-out region.
+    PulloutText,
+    /// A partition belonging to an equation region.
+    Equation,
+    /// A partition belonging to an inline equation region.
+    InlineEquation,
+    /// A partition belonging to a table region.
+    Table,
+    /// Text line written in the vertical direction.
+    VerticalText,
+    /// Text that belongs to an image.
+    CaptionText,
+    /// An image that lives inside a column.
+    FlowingImage,
+    /// An image that spans more than one column.
+    HeadingImage,
+    /// An image that is in a cross-column pull-out region.
+    PulloutImage,
+    /// A horizontal line.
+    HorzLine,
+    /// A vertical line.
+    VertLine,
+    /// Lies outside of any column.
+    Noise,
+}
+
+#[derive(Debug, Error)]
+#[error("{0} is not a valid TessPolyBlockType")]
+pub struct PolyBlockTypeFromRawError(pub TessPolyBlockType);
+
+impl PolyBlockType {
+    /// Every `PolyBlockType`, in the order Tesseract declares them.
+    pub const ALL: [PolyBlockType; 15] = [
+        PolyBlockType::Unknown,
+        PolyBlockType::FlowingText,
+        PolyBlockType::HeadingText,
+        PolyBlockType::PulloutText,
+        PolyBlockType::Equation,
+        PolyBlockType::InlineEquation,
+        PolyBlockType::Table,
+        PolyBlockType::VerticalText,
+        PolyBlockType::CaptionText,
+        PolyBlockType::FlowingImage,
+        PolyBlockType::HeadingImage,
+        PolyBlockType::PulloutImage,
+        PolyBlockType::HorzLine,
+        PolyBlockType::VertLine,
+        PolyBlockType::Noise,
+    ];
+}
+
+impl From<PolyBlockType> for TessPolyBlockType {
+    fn from(value: PolyBlockType) -> Self {
+        match value {
+            PolyBlockType::Unknown => TessPolyBlockType_PT_UNKNOWN,
+            PolyBlockType::FlowingText => TessPolyBlockType_PT_FLOWING_TEXT,
+            PolyBlockType::HeadingText => TessPolyBlockType_PT_HEADING_TEXT,
+            PolyBlockType::PulloutText => TessPolyBlockType_PT_PULLOUT_TEXT,
+            PolyBlockType::Equation => TessPolyBlockType_PT_EQUATION,
+            PolyBlockType::InlineEquation => TessPolyBlockType_PT_INLINE_EQUATION,
+            PolyBlockType::Table => TessPolyBlockType_PT_TABLE,
+            PolyBlockType::VerticalText => TessPolyBlockType_PT_VERTICAL_TEXT,
+            PolyBlockType::CaptionText => TessPolyBlockType_PT_CAPTION_TEXT,
+            PolyBlockType::FlowingImage => TessPolyBlockType_PT_FLOWING_IMAGE,
+            PolyBlockType::HeadingImage => TessPolyBlockType_PT_HEADING_IMAGE,
+            PolyBlockType::PulloutImage => TessPolyBlockType_PT_PULLOUT_IMAGE,
+            PolyBlockType::HorzLine => TessPolyBlockType_PT_HORZ_LINE,
+            PolyBlockType::VertLine => TessPolyBlockType_PT_VERT_LINE,
+            PolyBlockType::Noise => TessPolyBlockType_PT_NOISE,
+        }
+    }
+}
+
+impl TryFrom<TessPolyBlockType> for PolyBlockType {
+    type Error = PolyBlockTypeFromRawError;
+
+    #[allow(non_upper_case_globals)]
+    fn try_from(raw: TessPolyBlockType) -> Result<Self, Self::Error> {
+        match raw {
+            TessPolyBlockType_PT_UNKNOWN => Ok(PolyBlockType::Unknown),
+            TessPolyBlockType_PT_FLOWING_TEXT => Ok(PolyBlockType::FlowingText),
+            TessPolyBlockType_PT_HEADING_TEXT => Ok(PolyBlockType::HeadingText),
+            TessPolyBlockType_PT_PULLOUT_TEXT => Ok(PolyBlockType::PulloutText),
+            TessPolyBlockType_PT_EQUATION => Ok(PolyBlockType::Equation),
+            TessPolyBlockType_PT_INLINE_EQUATION => Ok(PolyBlockType::InlineEquation),
+            TessPolyBlockType_PT_TABLE => Ok(PolyBlockType::Table),
+            TessPolyBlockType_PT_VERTICAL_TEXT => Ok(PolyBlockType::VerticalText),
+            TessPolyBlockType_PT_CAPTION_TEXT => Ok(PolyBlockType::CaptionText),
+            TessPolyBlockType_PT_FLOWING_IMAGE => Ok(PolyBlockType::FlowingImage),
+            TessPolyBlockType_PT_HEADING_IMAGE => Ok(PolyBlockType::HeadingImage),
+            TessPolyBlockType_PT_PULLOUT_IMAGE => Ok(PolyBlockType::PulloutImage),
+            TessPolyBlockType_PT_HORZ_LINE => Ok(PolyBlockType::HorzLine),
+            TessPolyBlockType_PT_VERT_LINE => Ok(PolyBlockType::VertLine),
+            TessPolyBlockType_PT_NOISE => Ok(PolyBlockType::Noise),
+            _ => Err(PolyBlockTypeFromRawError(raw)),
+        }
+    }
+}
+
+#[test]
+fn poly_block_type_conversion_test() {
+    for value in PolyBlockType::ALL.iter().copied() {
+        assert_eq!(
+            PolyBlockType::try_from(TessPolyBlockType::from(value)).ok(),
+            Some(value)
+        );
+    }
+    assert_eq!(
+        TessPolyBlockType::from(PolyBlockType::Table),
+        tesseract_sys::TessPolyBlockType_PT_TABLE
+    );
+    assert!(PolyBlockType::try_from(tesseract_sys::TessPolyBlockType_PT_COUNT).is_err());
+}
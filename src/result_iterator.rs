@@ -0,0 +1,260 @@
+extern crate tesseract_sys;
+extern crate thiserror;
+
+use self::tesseract_sys::{
+    TessPageIteratorBoundingBox, TessPageIteratorCopy, TessPageIteratorIsAtBeginningOf,
+    TessResultIteratorConfidence, TessResultIteratorDelete, TessResultIteratorGetChoiceIterator,
+    TessResultIteratorGetPageIteratorConst, TessResultIteratorGetUTF8Text, TessResultIteratorNext,
+    TessResultIteratorSymbolIsDropcap, TessResultIteratorSymbolIsSubscript,
+    TessResultIteratorSymbolIsSuperscript, TessResultIteratorWordFontAttributes,
+    TessResultIteratorWordIsFromDictionary, TessResultIteratorWordIsNumeric,
+    TessResultIteratorWordRecognitionLanguage,
+};
+use self::thiserror::Error;
+use crate::{ChoiceIterator, PageIterator, PageIteratorLevel, Text};
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::os::raw::c_char;
+use std::os::raw::{c_float, c_int};
+
+/// Wrapper around [`TessResultIterator`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html)
+///
+/// Borrows the `TessBaseApi` that produced it, so it can't outlive the recognition results.
+#[derive(Debug)]
+pub struct ResultIterator<'a>(
+    *mut tesseract_sys::TessResultIterator,
+    PhantomData<&'a mut tesseract_sys::TessBaseAPI>,
+);
+
+unsafe impl Send for ResultIterator<'_> {}
+
+impl Drop for ResultIterator<'_> {
+    fn drop(&mut self) {
+        unsafe { TessResultIteratorDelete(self.0) }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("ResultIterator get_utf8_text returned null")]
+pub struct ResultIteratorGetUtf8TextError();
+
+/// The font and recognition metadata of a word, returned by `ResultIterator::word_attributes`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WordAttributes {
+    /// The name of the font, or `None` if the engine has no font information (as with the LSTM engine).
+    pub font_name: Option<String>,
+    pub is_bold: bool,
+    pub is_italic: bool,
+    pub is_underlined: bool,
+    pub is_monospace: bool,
+    pub is_serif: bool,
+    pub is_smallcaps: bool,
+    /// The size of the font in printer's points (1/72 inch), estimated from the line height and image resolution.
+    pub pointsize: c_int,
+    /// Tesseract's id for the font, or -1 if there is no font information.
+    pub font_id: c_int,
+    /// The language the word was recognized in, such as `eng`.
+    pub language: Option<String>,
+    /// Whether the word was found in the language's dictionary.
+    pub is_from_dictionary: bool,
+    /// Whether the word is a number.
+    pub is_numeric: bool,
+    /// Whether the current symbol (the first of the word, at word level) is superscript.
+    pub is_superscript: bool,
+    /// Whether the current symbol (the first of the word, at word level) is subscript.
+    pub is_subscript: bool,
+    /// Whether the current symbol (the first of the word, at word level) is a dropcap.
+    pub is_dropcap: bool,
+}
+
+fn optional_string(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(
+            unsafe { CStr::from_ptr(ptr) }
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+}
+
+impl<'a> ResultIterator<'a> {
+    /// # Safety
+    ///
+    /// This function should only be called with a valid, non-null result iterator pointer from Tesseract.
+    /// `ResultIterator` will be responsible for freeing it.
+    /// The lifetime must not exceed that of the `TessBaseAPI` it came from.
+    pub unsafe fn new(raw: *mut tesseract_sys::TessResultIterator) -> Self {
+        Self(raw, PhantomData)
+    }
+
+    /// Wrapper for [`TessResultIteratorGetPageIteratorConst`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a6aa49c03b6c974552bc2a6df356ce024) and [`TessPageIteratorCopy`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#aca14dd11d9f0366a28571d8bee92ebd1)
+    ///
+    /// Tesseract's page iterator is the result iterator itself rather than a separate allocation,
+    /// so this returns an independent copy positioned at the same element.
+    pub fn get_page_iterator(&self) -> PageIterator<'a> {
+        unsafe {
+            PageIterator::new(TessPageIteratorCopy(
+                TessResultIteratorGetPageIteratorConst(self.0),
+            ))
+        }
+    }
+
+    /// Wrapper for [`TessResultIteratorGetChoiceIterator`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#ad5813100d215ab545d7ed86d9dd5333c)
+    ///
+    /// Get the alternative hypotheses for the current symbol.
+    /// The iterator should be positioned at a symbol (`PageIteratorLevel::Symbol`) for this to be meaningful.
+    ///
+    /// With the LSTM engine, the choice iterator is only populated if the `lstm_choice_mode` variable was set
+    /// (e.g. to `2`) before recognition.
+    ///
+    /// Returns `None` if there is no current symbol, e.g. on an empty page or after `next` has returned `false`.
+    pub fn get_choice_iterator(&self) -> Option<ChoiceIterator<'_>> {
+        // Tesseract asserts that there is a current word rather than returning null.
+        self.bounding_box(PageIteratorLevel::Symbol)?;
+        Some(unsafe { ChoiceIterator::new(TessResultIteratorGetChoiceIterator(self.0)) })
+    }
+
+    /// Wrapper for [`TessResultIteratorNext`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a3dbc40c8bca27fe4d92236fc877e8710)
+    ///
+    /// Moves to the start of the next object at the given level. Returns `false` if the end of the page was reached.
+    pub fn next(&mut self, level: PageIteratorLevel) -> bool {
+        unsafe { TessResultIteratorNext(self.0, level.into()) != 0 }
+    }
+
+    /// Wrapper for [`TessPageIteratorIsAtBeginningOf`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#ae1408d62c04b60e4aab18a935bb1a46f)
+    ///
+    /// Returns `true` if the iterator is at the start of an object at the given level.
+    pub fn is_at_beginning_of(&self, level: PageIteratorLevel) -> bool {
+        unsafe {
+            TessPageIteratorIsAtBeginningOf(
+                TessResultIteratorGetPageIteratorConst(self.0),
+                level.into(),
+            ) != 0
+        }
+    }
+
+    /// Wrapper for [`TessResultIteratorGetUTF8Text`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#ab42727357e2d2d258b3b2d5f3065b942)
+    ///
+    /// Returns the text of the current object at the given level.
+    ///
+    /// Returns an error (null pointer) if there is no text at the current position.
+    pub fn get_utf8_text(
+        &self,
+        level: PageIteratorLevel,
+    ) -> Result<Text, ResultIteratorGetUtf8TextError> {
+        let ptr = unsafe { TessResultIteratorGetUTF8Text(self.0, level.into()) };
+        if ptr.is_null() {
+            Err(ResultIteratorGetUtf8TextError {})
+        } else {
+            Ok(unsafe { Text::new(ptr) })
+        }
+    }
+
+    /// Wrapper for [`TessResultIteratorConfidence`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#aeccc325de296c42043dea4c2c7ab06d6)
+    ///
+    /// Returns the mean certainty (between 0 and 100) of the current object at the given level.
+    pub fn confidence(&self, level: PageIteratorLevel) -> c_float {
+        unsafe { TessResultIteratorConfidence(self.0, level.into()) }
+    }
+
+    /// Wrapper for [`TessResultIteratorWordFontAttributes`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#ac89500e2992bb69464a1294ea994e544), [`TessResultIteratorWordRecognitionLanguage`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a6ceac987f3adefffe2bc2a9868f92b68), [`TessResultIteratorWordIsFromDictionary`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a83e7210ae890cfd3f617a51c15f2f9f1), [`TessResultIteratorWordIsNumeric`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a219321b6671d9869f375587a662e6303), [`TessResultIteratorSymbolIsSuperscript`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#acf4d4fdc56e4035a177fe03b6edd6985), [`TessResultIteratorSymbolIsSubscript`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#af4fbf6a286c32280a84a9a8b6c57ef69) and [`TessResultIteratorSymbolIsDropcap`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#ae4c1c439347f8daee831f8d026cb9498)
+    ///
+    /// Returns the attributes of the current word. The iterator should be positioned at a word (`PageIteratorLevel::Word`).
+    pub fn word_attributes(&self) -> WordAttributes {
+        let (mut is_bold, mut is_italic, mut is_underlined) = (0, 0, 0);
+        let (mut is_monospace, mut is_serif, mut is_smallcaps) = (0, 0, 0);
+        let (mut pointsize, mut font_id) = (0, -1);
+        let font_name = unsafe {
+            TessResultIteratorWordFontAttributes(
+                self.0,
+                &mut is_bold,
+                &mut is_italic,
+                &mut is_underlined,
+                &mut is_monospace,
+                &mut is_serif,
+                &mut is_smallcaps,
+                &mut pointsize,
+                &mut font_id,
+            )
+        };
+        unsafe {
+            WordAttributes {
+                font_name: optional_string(font_name),
+                is_bold: is_bold != 0,
+                is_italic: is_italic != 0,
+                is_underlined: is_underlined != 0,
+                is_monospace: is_monospace != 0,
+                is_serif: is_serif != 0,
+                is_smallcaps: is_smallcaps != 0,
+                pointsize,
+                font_id,
+                language: optional_string(TessResultIteratorWordRecognitionLanguage(self.0)),
+                is_from_dictionary: TessResultIteratorWordIsFromDictionary(self.0) != 0,
+                is_numeric: TessResultIteratorWordIsNumeric(self.0) != 0,
+                is_superscript: self.symbol_is_superscript(),
+                is_subscript: self.symbol_is_subscript(),
+                is_dropcap: self.symbol_is_dropcap(),
+            }
+        }
+    }
+
+    /// Wrapper for [`TessResultIteratorSymbolIsSuperscript`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#acf4d4fdc56e4035a177fe03b6edd6985)
+    ///
+    /// Returns whether the current symbol is superscript.
+    pub fn symbol_is_superscript(&self) -> bool {
+        unsafe { TessResultIteratorSymbolIsSuperscript(self.0) != 0 }
+    }
+
+    /// Wrapper for [`TessResultIteratorSymbolIsSubscript`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#af4fbf6a286c32280a84a9a8b6c57ef69)
+    ///
+    /// Returns whether the current symbol is subscript.
+    pub fn symbol_is_subscript(&self) -> bool {
+        unsafe { TessResultIteratorSymbolIsSubscript(self.0) != 0 }
+    }
+
+    /// Wrapper for [`TessResultIteratorSymbolIsDropcap`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#ae4c1c439347f8daee831f8d026cb9498)
+    ///
+    /// Returns whether the current symbol is a dropcap.
+    pub fn symbol_is_dropcap(&self) -> bool {
+        unsafe { TessResultIteratorSymbolIsDropcap(self.0) != 0 }
+    }
+
+    /// Wrapper for [`TessPageIteratorBoundingBox`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a419724c02486a038a1c185417fc9c277)
+    ///
+    /// Returns the bounding rectangle of the current object at the given level as `(left, top, right, bottom)` in image pixel coordinates.
+    ///
+    /// Returns `None` if there is no such object at the current position.
+    pub fn bounding_box(&self, level: PageIteratorLevel) -> Option<(c_int, c_int, c_int, c_int)> {
+        let (mut left, mut top, mut right, mut bottom) = (0, 0, 0, 0);
+        let ret = unsafe {
+            TessPageIteratorBoundingBox(
+                TessResultIteratorGetPageIteratorConst(self.0),
+                level.into(),
+                &mut left,
+                &mut top,
+                &mut right,
+                &mut bottom,
+            )
+        };
+        if ret == 0 {
+            None
+        } else {
+            Some((left, top, right, bottom))
+        }
+    }
+}
+
+#[test]
+fn word_attributes_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (mut tess, _pix) = crate::test_api()?;
+    tess.recognize()?;
+    let attributes = tess.get_iterator().unwrap().word_attributes();
+    assert_eq!(attributes.language.as_deref(), Some("eng"));
+    assert!(attributes.pointsize > 0);
+    assert!(!attributes.is_numeric);
+    assert!(!attributes.is_superscript && !attributes.is_subscript && !attributes.is_dropcap);
+    Ok(())
+}
@@ -0,0 +1,185 @@
+extern crate tesseract_sys;
+extern crate thiserror;
+
+use self::tesseract_sys::{
+    TessAltoRendererCreate, TessBoxTextRendererCreate, TessDeleteResultRenderer,
+    TessHOcrRendererCreate, TessLSTMBoxRendererCreate, TessResultRendererAddImage,
+    TessResultRendererBeginDocument, TessResultRendererEndDocument, TessResultRendererExtention,
+    TessResultRendererImageNum, TessResultRendererInsert, TessTextRendererCreate,
+    TessTsvRendererCreate, TessUnlvRendererCreate, TessWordStrBoxRendererCreate,
+};
+use self::thiserror::Error;
+use crate::TessBaseApi;
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::c_int;
+
+/// Wrapper around [`TessResultRenderer`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html)
+///
+/// Writes the recognition results of one or more pages as a document.
+/// `outputbase` is the output filename without its extension, or `-` to write to stdout.
+#[derive(Debug)]
+pub struct ResultRenderer(*mut tesseract_sys::TessResultRenderer);
+
+unsafe impl Send for ResultRenderer {}
+
+impl Drop for ResultRenderer {
+    fn drop(&mut self) {
+        unsafe { TessDeleteResultRenderer(self.0) }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("ResultRenderer failed to begin document")]
+pub struct ResultRendererBeginDocumentError();
+
+#[derive(Debug, Error)]
+#[error("ResultRenderer failed to add image")]
+pub struct ResultRendererAddImageError();
+
+#[derive(Debug, Error)]
+#[error("ResultRenderer failed to end document")]
+pub struct ResultRendererEndDocumentError();
+
+impl ResultRenderer {
+    /// # Safety
+    ///
+    /// This function should only be called with a valid, non-null renderer pointer from Tesseract.
+    /// `ResultRenderer` will be responsible for freeing it.
+    pub unsafe fn new(raw: *mut tesseract_sys::TessResultRenderer) -> Self {
+        Self(raw)
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut tesseract_sys::TessResultRenderer {
+        self.0
+    }
+
+    /// Wrapper for [`TessTextRendererCreate`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a70385524422e4e72469d95ff54bc79c6)
+    pub fn create_text(outputbase: &CStr) -> Self {
+        Self(unsafe { TessTextRendererCreate(outputbase.as_ptr()) })
+    }
+
+    /// Wrapper for [`TessHOcrRendererCreate`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#aea5c360e4f49067489fe993f07468929)
+    pub fn create_hocr(outputbase: &CStr) -> Self {
+        Self(unsafe { TessHOcrRendererCreate(outputbase.as_ptr()) })
+    }
+
+    /// Wrapper for [`TessAltoRendererCreate`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#aa9c8144c0c89e1a0dfaa599cf257cc64)
+    pub fn create_alto(outputbase: &CStr) -> Self {
+        Self(unsafe { TessAltoRendererCreate(outputbase.as_ptr()) })
+    }
+
+    /// Wrapper for [`TessTsvRendererCreate`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#ab85e0f74ddd42d44e352cdfcb029e426)
+    pub fn create_tsv(outputbase: &CStr) -> Self {
+        Self(unsafe { TessTsvRendererCreate(outputbase.as_ptr()) })
+    }
+
+    /// Wrapper for [`TessUnlvRendererCreate`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a5cea574df8b90d9281c62229e9d47cb5)
+    pub fn create_unlv(outputbase: &CStr) -> Self {
+        Self(unsafe { TessUnlvRendererCreate(outputbase.as_ptr()) })
+    }
+
+    /// Wrapper for [`TessBoxTextRendererCreate`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a826023bd6f3cfd2f122bec9d8a6b13d8)
+    pub fn create_box_text(outputbase: &CStr) -> Self {
+        Self(unsafe { TessBoxTextRendererCreate(outputbase.as_ptr()) })
+    }
+
+    /// Wrapper for [`TessLSTMBoxRendererCreate`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a30408d6cc833a6a9daacbb66f22ad7ec)
+    pub fn create_lstm_box(outputbase: &CStr) -> Self {
+        Self(unsafe { TessLSTMBoxRendererCreate(outputbase.as_ptr()) })
+    }
+
+    /// Wrapper for [`TessWordStrBoxRendererCreate`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a82571cfeb9f43307eaeaacbc9b3f6061)
+    pub fn create_word_str_box(outputbase: &CStr) -> Self {
+        Self(unsafe { TessWordStrBoxRendererCreate(outputbase.as_ptr()) })
+    }
+
+    /// Wrapper for [`TessResultRendererInsert`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#ac7d1597ac57f4a1b5cf9d30e8aebb774)
+    ///
+    /// Chain another renderer after this one, so that both render every document.
+    /// This renderer takes ownership of `next` and frees it when dropped.
+    pub fn insert(&mut self, next: ResultRenderer) {
+        let next_ptr = next.0;
+        mem::forget(next);
+        unsafe { TessResultRendererInsert(self.0, next_ptr) }
+    }
+
+    /// Wrapper for [`TessResultRendererBeginDocument`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a06b03e65d08b88204c736f05875b1488)
+    ///
+    /// Start a new document with the given title. Must be called before `add_image`.
+    pub fn begin_document(&mut self, title: &CStr) -> Result<(), ResultRendererBeginDocumentError> {
+        let ret = unsafe { TessResultRendererBeginDocument(self.0, title.as_ptr()) };
+        match ret {
+            0 => Err(ResultRendererBeginDocumentError {}),
+            _ => Ok(()),
+        }
+    }
+
+    /// Wrapper for [`TessResultRendererAddImage`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a47311b3f4fe424b9e5fa13912c3fb9b0)
+    ///
+    /// Add the current page of `api` to the document. This will implicitly call `recognize` if required.
+    pub fn add_image(&mut self, api: &mut TessBaseApi) -> Result<(), ResultRendererAddImageError> {
+        let ret = unsafe { TessResultRendererAddImage(self.0, api.as_mut_ptr()) };
+        match ret {
+            0 => Err(ResultRendererAddImageError {}),
+            _ => Ok(()),
+        }
+    }
+
+    /// Wrapper for [`TessResultRendererEndDocument`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a7df42c8ba0b496b336e3b730a959d452)
+    ///
+    /// Finish the document, writing any footers.
+    pub fn end_document(&mut self) -> Result<(), ResultRendererEndDocumentError> {
+        let ret = unsafe { TessResultRendererEndDocument(self.0) };
+        match ret {
+            0 => Err(ResultRendererEndDocumentError {}),
+            _ => Ok(()),
+        }
+    }
+
+    /// Wrapper for [`TessResultRendererExtention`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a18734487f5d1d20781b98af5464b3a37)
+    ///
+    /// Returns the file extension of this renderer, such as `txt`.
+    pub fn get_extension(&self) -> &CStr {
+        unsafe { CStr::from_ptr(TessResultRendererExtention(self.0)) }
+    }
+
+    /// Wrapper for [`TessResultRendererImageNum`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#adf79912db3af5ec470ecc00688066328)
+    ///
+    /// Returns the index of the last image added, or -1 if none have been added.
+    pub fn get_image_num(&self) -> c_int {
+        unsafe { TessResultRendererImageNum(self.0) }
+    }
+}
+
+#[test]
+fn result_renderer_test() -> Result<(), Box<dyn std::error::Error>> {
+    use std::ffi::CString;
+    use std::fs;
+
+    let outputbase = std::env::temp_dir().join(format!(
+        "tesseract-plumbing-result-renderer-test-{}",
+        std::process::id()
+    ));
+    let c_outputbase = CString::new(outputbase.to_str().unwrap())?;
+
+    let (mut tess, _pix) = crate::test_api()?;
+
+    let mut renderer = ResultRenderer::create_text(&c_outputbase);
+    renderer.insert(ResultRenderer::create_tsv(&c_outputbase));
+    assert_eq!(renderer.get_extension().to_str()?, "txt");
+    assert_eq!(renderer.get_image_num(), -1);
+    renderer.begin_document(&CString::new("title")?)?;
+    renderer.add_image(&mut tess)?;
+    assert_eq!(renderer.get_image_num(), 0);
+    renderer.end_document()?;
+    drop(renderer);
+
+    let text = fs::read_to_string(outputbase.with_extension("txt"))?;
+    let tsv = fs::read_to_string(outputbase.with_extension("tsv"))?;
+    fs::remove_file(outputbase.with_extension("txt"))?;
+    fs::remove_file(outputbase.with_extension("tsv"))?;
+    assert!(text.contains("tesseract_plumbing"));
+    assert!(tsv.contains("tesseract_plumbing"));
+    Ok(())
+}
@@ -4,29 +4,51 @@ extern crate thiserror;
 #[cfg(feature = "tesseract_5_2")]
 use self::tesseract_sys::TessBaseAPIInit5;
 use self::tesseract_sys::{
-    TessBaseAPIAllWordConfidences, TessBaseAPICreate, TessBaseAPIDelete, TessBaseAPIGetAltoText,
-    TessBaseAPIGetComponentImages, TessBaseAPIGetHOCRText, TessBaseAPIGetInputImage,
-    TessBaseAPIGetLSTMBoxText, TessBaseAPIGetSourceYResolution, TessBaseAPIGetTsvText,
-    TessBaseAPIGetUTF8Text, TessBaseAPIGetWordStrBoxText, TessBaseAPIInit2, TessBaseAPIInit3,
-    TessBaseAPIMeanTextConf, TessBaseAPIRecognize, TessBaseAPISetImage, TessBaseAPISetImage2,
-    TessBaseAPISetPageSegMode, TessBaseAPISetRectangle, TessBaseAPISetSourceResolution,
-    TessBaseAPISetVariable, TessDeleteIntArray, TessOcrEngineMode, TessPageIteratorLevel,
-    TessPageSegMode,
+    TessBaseAPIAllWordConfidences, TessBaseAPIAnalyseLayout, TessBaseAPICreate, TessBaseAPIDelete,
+    TessBaseAPIDetectOrientationScript, TessBaseAPIGetAltoText,
+    TessBaseAPIGetAvailableLanguagesAsVector, TessBaseAPIGetBoolVariable,
+    TessBaseAPIGetComponentImages, TessBaseAPIGetComponentImages1,
+    TessBaseAPIGetConnectedComponents, TessBaseAPIGetDatapath, TessBaseAPIGetDoubleVariable,
+    TessBaseAPIGetHOCRText, TessBaseAPIGetInitLanguagesAsString, TessBaseAPIGetInputImage,
+    TessBaseAPIGetIntVariable, TessBaseAPIGetIterator, TessBaseAPIGetLSTMBoxText,
+    TessBaseAPIGetLoadedLanguagesAsVector, TessBaseAPIGetOsdText, TessBaseAPIGetPageSegMode,
+    TessBaseAPIGetRegions, TessBaseAPIGetSourceYResolution, TessBaseAPIGetStringVariable,
+    TessBaseAPIGetStrips, TessBaseAPIGetTextlines, TessBaseAPIGetThresholdedImage,
+    TessBaseAPIGetThresholdedImageScaleFactor, TessBaseAPIGetTsvText, TessBaseAPIGetUTF8Text,
+    TessBaseAPIGetWordStrBoxText, TessBaseAPIGetWords, TessBaseAPIInit2, TessBaseAPIInit3,
+    TessBaseAPIInit4, TessBaseAPIInitForAnalysePage, TessBaseAPIMeanTextConf, TessBaseAPIOem,
+    TessBaseAPIPrintVariablesToFile, TessBaseAPIProcessPage, TessBaseAPIProcessPages,
+    TessBaseAPIRecognize, TessBaseAPISetImage, TessBaseAPISetImage2, TessBaseAPISetPageSegMode,
+    TessBaseAPISetRectangle, TessBaseAPISetSourceResolution, TessBaseAPISetVariable,
+    TessDeleteIntArray, TessDeleteText, TessDeleteTextArray, TessOcrEngineMode,
+    TessPageIteratorLevel, TessPageSegMode,
 };
 
 use self::thiserror::Error;
-use crate::Text;
-use leptonica_plumbing::Pix;
-use std::convert::TryInto;
-use std::ffi::CStr;
+use crate::{
+    Monitor, OcrEngineMode, OcrEngineModeFromRawError, Page, PageIterator, PageIteratorLevel,
+    PageSegMode, ResultIterator, ResultRenderer, Text,
+};
+use leptonica_plumbing::memory::{RefCounted, RefCountedExclusive};
+use leptonica_plumbing::{Boxa, Pix, Pixa};
+use std::convert::{TryFrom, TryInto};
+use std::env;
+use std::ffi::{CStr, CString};
+use std::fs;
+use std::io;
 use std::ops::{Deref, DerefMut};
-use std::os::raw::c_int;
+use std::os::raw::{c_char, c_double, c_float, c_int};
+use std::process;
 use std::ptr;
 use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// Wrapper around [`tesseract::TessBaseAPI`](https://tesseract-ocr.github.io/tessapi/5.x/a02438.html)
+///
+/// The `bool` records whether Tesseract's internal engine has been created (by `init_*`, `set_variable` or `set_page_seg_mode`).
+/// Some Tesseract functions dereference the engine without checking for null.
 #[derive(Debug)]
-pub struct TessBaseApi(*mut tesseract_sys::TessBaseAPI);
+pub struct TessBaseApi(*mut tesseract_sys::TessBaseAPI, bool);
 
 unsafe impl Send for TessBaseApi {}
 
@@ -92,10 +114,60 @@ pub struct TessBaseApiGetWordStrBoxTextError();
 #[error("TessBaseApi get_component_images returned null")]
 pub struct TessBaseApiGetComponentImagesError();
 
+#[derive(Debug, Error)]
+#[error("TessBaseApi get_regions returned null")]
+pub struct TessBaseApiGetRegionsError();
+
+#[derive(Debug, Error)]
+#[error("TessBaseApi get_textlines returned null")]
+pub struct TessBaseApiGetTextlinesError();
+
+#[derive(Debug, Error)]
+#[error("TessBaseApi get_strips returned null")]
+pub struct TessBaseApiGetStripsError();
+
+#[derive(Debug, Error)]
+#[error("TessBaseApi get_words returned null")]
+pub struct TessBaseApiGetWordsError();
+
+#[derive(Debug, Error)]
+#[error("TessBaseApi get_connected_components returned null")]
+pub struct TessBaseApiGetConnectedComponentsError();
+
+#[derive(Debug, Error)]
+#[error("TessBaseApi get_osd_text returned null")]
+pub struct TessBaseApiGetOsdTextError();
+
+#[derive(Debug, Error)]
+#[error("TessBaseApi failed to detect orientation and script")]
+pub struct TessBaseApiDetectOrientationScriptError();
+
 #[derive(Debug, Error)]
 #[error("TessBaseApi all_word_confidences returned null")]
 pub struct TessBaseApiAllWordConfidencesError();
 
+#[derive(Debug, Error)]
+#[error("TessBaseApi print_variables_to_file failed")]
+pub struct TessBaseApiPrintVariablesToFileError();
+
+#[derive(Debug, Error)]
+pub enum TessBaseApiGetVariablesError {
+    #[error("Temporary file path can't be passed to Tesseract")]
+    TemporaryFilePath(),
+    #[error(transparent)]
+    PrintVariablesToFile(#[from] TessBaseApiPrintVariablesToFileError),
+    #[error("Failed to create or read the temporary file")]
+    Io(#[from] io::Error),
+}
+
+#[derive(Debug, Error)]
+#[error("TessBaseApi failed to process pages")]
+pub struct TessBaseApiProcessPagesError();
+
+#[derive(Debug, Error)]
+#[error("TessBaseApi failed to process page")]
+pub struct TessBaseApiProcessPageError();
+
 pub struct AllWordConfidences(*mut c_int, usize);
 
 impl AllWordConfidences {
@@ -130,9 +202,93 @@ impl Drop for AllWordConfidences {
     }
 }
 
+/// The result of `TessBaseApi::detect_orientation_script`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrientationScript {
+    /// How far clockwise the page is rotated: 0, 90, 180 or 270.
+    /// Rotate the image anticlockwise by this much to make it upright.
+    pub orientation_degrees: c_int,
+    /// The confidence of the orientation.
+    pub orientation_confidence: c_float,
+    /// The name of the detected script, such as `Latin`.
+    pub script_name: String,
+    /// The confidence of the script.
+    pub script_confidence: c_float,
+}
+
+/// The components returned by `TessBaseApi::get_component_images`, in reading order.
+///
+/// The `n`th entry of each field describes the same component.
+pub struct ComponentImages {
+    /// The bounding box of each component.
+    pub boxes: RefCountedExclusive<Boxa>,
+    /// The image of each component, cropped from the thresholded or original image.
+    pub images: RefCountedExclusive<Pixa>,
+    /// The index of the block containing each component.
+    pub block_ids: Vec<c_int>,
+    /// The index of the paragraph, within its block, containing each component.
+    pub para_ids: Vec<c_int>,
+}
+
+impl std::fmt::Debug for ComponentImages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComponentImages")
+            .field("boxes", &*self.boxes)
+            .field("images", &*self.images)
+            .field("block_ids", &self.block_ids)
+            .field("para_ids", &self.para_ids)
+            .finish()
+    }
+}
+
+/// Tesseract takes arrays of strings as `char **`, but doesn't mutate them.
+fn c_string_array<'a>(strings: impl Iterator<Item = &'a CStr>) -> Vec<*mut c_char> {
+    strings
+        .map(|string| string.as_ptr() as *mut c_char)
+        .collect()
+}
+
+/// Copy a null terminated array of strings from Tesseract, then free it.
+///
+/// # Safety
+///
+/// `arr` must be null or a valid array from Tesseract that hasn't already been freed.
+unsafe fn take_text_array(arr: *mut *mut c_char) -> Vec<String> {
+    let mut strings = Vec::new();
+    if arr.is_null() {
+        return strings;
+    }
+    let mut item = arr;
+    while !(*item).is_null() {
+        strings.push(CStr::from_ptr(*item).to_string_lossy().into_owned());
+        item = item.add(1);
+    }
+    TessDeleteTextArray(arr);
+    strings
+}
+
+/// Copy an array of `len` ints from Tesseract, then free it.
+///
+/// # Safety
+///
+/// `arr` must be null or a valid array from Tesseract, at least `len` long, that hasn't already been freed.
+unsafe fn take_int_array(arr: *mut c_int, len: usize) -> Vec<c_int> {
+    if arr.is_null() {
+        return Vec::new();
+    }
+    let ints = slice::from_raw_parts(arr, len).to_vec();
+    TessDeleteIntArray(arr);
+    ints
+}
+
 impl TessBaseApi {
     pub fn create() -> Self {
-        Self(unsafe { TessBaseAPICreate() })
+        Self(unsafe { TessBaseAPICreate() }, false)
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut tesseract_sys::TessBaseAPI {
+        self.0
     }
 
     #[cfg(feature = "tesseract_5_2")]
@@ -158,6 +314,7 @@ impl TessBaseApi {
                 0,
             )
         };
+        self.1 = ret == 0;
         if ret == 0 {
             Ok(())
         } else {
@@ -180,6 +337,7 @@ impl TessBaseApi {
                 language.map(CStr::as_ptr).unwrap_or_else(ptr::null),
             )
         };
+        self.1 = ret == 0;
         if ret == 0 {
             Ok(())
         } else {
@@ -202,6 +360,7 @@ impl TessBaseApi {
                 oem,
             )
         };
+        self.1 = ret == 0;
         if ret == 0 {
             Ok(())
         } else {
@@ -209,6 +368,137 @@ impl TessBaseApi {
         }
     }
 
+    #[cfg(feature = "tesseract_5_2")]
+    /// Wrapper for [`Init-1`](https://tesseract-ocr.github.io/tessapi/5.x/a02438.html#a2be07b4c9449b8cfc43e9c26ee623050)
+    ///
+    /// Like `init_1`, but also reads the `configs` files and sets the init-only `vars` (`(name, value)` pairs).
+    /// If `set_only_non_debug_params` is true, only parameters that don't have a debug prefix are set from the config files.
+    pub fn init_1_with_config(
+        &mut self,
+        data: &[u8],
+        language: Option<&CStr>,
+        oem: TessOcrEngineMode,
+        configs: &[&CStr],
+        vars: &[(&CStr, &CStr)],
+        set_only_non_debug_params: bool,
+    ) -> Result<(), TessBaseApiInitError> {
+        let mut configs = c_string_array(configs.iter().copied());
+        let mut vars_vec = c_string_array(vars.iter().map(|(name, _)| *name));
+        let mut vars_values = c_string_array(vars.iter().map(|(_, value)| *value));
+        let ret = unsafe {
+            TessBaseAPIInit5(
+                self.0,
+                data.as_ptr().cast(),
+                data.len() as c_int,
+                language.map(CStr::as_ptr).unwrap_or_else(ptr::null),
+                oem,
+                configs.as_mut_ptr(),
+                configs.len() as c_int,
+                vars_vec.as_mut_ptr(),
+                vars_values.as_mut_ptr(),
+                vars.len(),
+                set_only_non_debug_params as c_int,
+            )
+        };
+        self.1 = ret == 0;
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(TessBaseApiInitError {})
+        }
+    }
+
+    /// Wrapper for [`TessBaseAPIInit4`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a50c033a70969810464e60afb2196c400)
+    ///
+    /// Like `init_4`, but also reads the `configs` files and sets the init-only `vars` (`(name, value)` pairs).
+    /// If `set_only_non_debug_params` is true, only parameters that don't have a debug prefix are set from the config files.
+    pub fn init_with_config(
+        &mut self,
+        datapath: Option<&CStr>,
+        language: Option<&CStr>,
+        oem: TessOcrEngineMode,
+        configs: &[&CStr],
+        vars: &[(&CStr, &CStr)],
+        set_only_non_debug_params: bool,
+    ) -> Result<(), TessBaseApiInitError> {
+        let mut configs = c_string_array(configs.iter().copied());
+        let mut vars_vec = c_string_array(vars.iter().map(|(name, _)| *name));
+        let mut vars_values = c_string_array(vars.iter().map(|(_, value)| *value));
+        let ret = unsafe {
+            TessBaseAPIInit4(
+                self.0,
+                datapath.map(CStr::as_ptr).unwrap_or_else(ptr::null),
+                language.map(CStr::as_ptr).unwrap_or_else(ptr::null),
+                oem,
+                configs.as_mut_ptr(),
+                configs.len() as c_int,
+                vars_vec.as_mut_ptr(),
+                vars_values.as_mut_ptr(),
+                vars.len(),
+                set_only_non_debug_params as c_int,
+            )
+        };
+        self.1 = ret == 0;
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(TessBaseApiInitError {})
+        }
+    }
+
+    /// Wrapper for [`TessBaseAPIOem`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a18fc825c0bb85a12cd8dce1e43b85587)
+    ///
+    /// Returns the engine mode requested by the last initialization, or `Default` before initialization.
+    pub fn oem(&self) -> Result<OcrEngineMode, OcrEngineModeFromRawError> {
+        OcrEngineMode::try_from(unsafe { TessBaseAPIOem(self.0) })
+    }
+
+    /// Wrapper for [`TessBaseAPIGetInitLanguagesAsString`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#ac8122d608c7d9bc425116e380ecd9c83)
+    ///
+    /// Returns the languages used in the last valid initialization, such as `["eng", "fra"]` for `eng+fra`.
+    /// This is empty before initialization.
+    pub fn get_init_languages(&self) -> Vec<String> {
+        unsafe { CStr::from_ptr(TessBaseAPIGetInitLanguagesAsString(self.0)) }
+            .to_string_lossy()
+            .split('+')
+            .filter(|language| !language.is_empty())
+            .map(str::to_owned)
+            .collect()
+    }
+
+    /// Wrapper for [`TessBaseAPIGetLoadedLanguagesAsVector`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a72ad3bbbe58f2c8e49da94d14e8c265a)
+    ///
+    /// Returns the languages that are loaded, including any loaded as dependencies of other languages.
+    pub fn get_loaded_languages(&self) -> Vec<String> {
+        unsafe { take_text_array(TessBaseAPIGetLoadedLanguagesAsVector(self.0)) }
+    }
+
+    /// Wrapper for [`TessBaseAPIGetAvailableLanguagesAsVector`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a33cb95278c30232d8eae9b7dab938e7b)
+    ///
+    /// Returns the languages available in the `tessdata` directory.
+    /// The directory is only known after initialization, so this is empty beforehand.
+    pub fn get_available_languages(&self) -> Vec<String> {
+        unsafe { take_text_array(TessBaseAPIGetAvailableLanguagesAsVector(self.0)) }
+    }
+
+    /// Wrapper for [`TessBaseAPIGetDatapath`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a65be86f2d41d2cb592295bcdf92b8a26)
+    ///
+    /// Returns the `tessdata` directory Tesseract was initialized with, or `None` if it hasn't been initialized.
+    pub fn get_datapath(&self) -> Option<&CStr> {
+        if !self.1 {
+            return None;
+        }
+        Some(unsafe { CStr::from_ptr(TessBaseAPIGetDatapath(self.0)) })
+    }
+
+    /// Wrapper for [`TessBaseAPIInitForAnalysePage`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a5c273ab585cca8c972d49dc351fb142b)
+    ///
+    /// Initialize tesseract just enough for `analyse_layout`. No language data is needed.
+    pub fn init_for_analyse_page(&mut self) {
+        unsafe { TessBaseAPIInitForAnalysePage(self.0) }
+        self.1 = true;
+    }
+
     /// Wrapper for [`SetImage-2`](https://tesseract-ocr.github.io/tessapi/5.x/a02438.html#a0c4c7f05fd58b3665b123232a05545ad)
     pub fn set_image_2(&mut self, pix: &Pix) {
         unsafe {
@@ -273,17 +563,168 @@ impl TessBaseApi {
         value: &CStr,
     ) -> Result<(), TessBaseApiSetVariableError> {
         let ret = unsafe { TessBaseAPISetVariable(self.0, name.as_ptr(), value.as_ptr()) };
+        self.1 = true;
         match ret {
             1 => Ok(()),
             _ => Err(TessBaseApiSetVariableError {}),
         }
     }
 
+    /// Wrapper for [`TessBaseAPIGetIntVariable`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#ab2e4fc8c2baaf5e4dbf517ca977f5c06)
+    ///
+    /// Returns `None` if `name` isn't an integer variable known to Tesseract, or Tesseract hasn't been initialized.
+    pub fn get_int_variable(&self, name: &CStr) -> Option<c_int> {
+        if !self.1 {
+            return None;
+        }
+        let mut value = 0;
+        let ret = unsafe { TessBaseAPIGetIntVariable(self.0, name.as_ptr(), &mut value) };
+        match ret {
+            0 => None,
+            _ => Some(value),
+        }
+    }
+
+    /// Wrapper for [`TessBaseAPIGetBoolVariable`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a095ae8700c01a78e95d556826cb70bdc)
+    ///
+    /// Returns `None` if `name` isn't a boolean variable known to Tesseract, or Tesseract hasn't been initialized.
+    pub fn get_bool_variable(&self, name: &CStr) -> Option<bool> {
+        if !self.1 {
+            return None;
+        }
+        let mut value = 0;
+        let ret = unsafe { TessBaseAPIGetBoolVariable(self.0, name.as_ptr(), &mut value) };
+        match ret {
+            0 => None,
+            _ => Some(value != 0),
+        }
+    }
+
+    /// Wrapper for [`TessBaseAPIGetDoubleVariable`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a3853ee99c6ca13c42759ebd022853b29)
+    ///
+    /// Returns `None` if `name` isn't a double variable known to Tesseract, or Tesseract hasn't been initialized.
+    pub fn get_double_variable(&self, name: &CStr) -> Option<c_double> {
+        if !self.1 {
+            return None;
+        }
+        let mut value = 0.0;
+        let ret = unsafe { TessBaseAPIGetDoubleVariable(self.0, name.as_ptr(), &mut value) };
+        match ret {
+            0 => None,
+            _ => Some(value),
+        }
+    }
+
+    /// Wrapper for [`TessBaseAPIGetStringVariable`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a06a30fc4904b00eb76dbfca6521744f7)
+    ///
+    /// Returns `None` if `name` isn't a string variable known to Tesseract, or Tesseract hasn't been initialized.
+    /// The string is owned by Tesseract and is only valid until the variable is next changed.
+    pub fn get_string_variable(&self, name: &CStr) -> Option<&CStr> {
+        if !self.1 {
+            return None;
+        }
+        let ptr = unsafe { TessBaseAPIGetStringVariable(self.0, name.as_ptr()) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(ptr) })
+        }
+    }
+
+    /// Wrapper for [`TessBaseAPIPrintVariablesToFile`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a57ec1b8c8bf3749ab36cc16f05171f82)
+    ///
+    /// Print Tesseract parameters to the given file. Fails if Tesseract hasn't been initialized.
+    pub fn print_variables_to_file(
+        &self,
+        filename: &CStr,
+    ) -> Result<(), TessBaseApiPrintVariablesToFileError> {
+        if !self.1 {
+            return Err(TessBaseApiPrintVariablesToFileError {});
+        }
+        let ret = unsafe { TessBaseAPIPrintVariablesToFile(self.0, filename.as_ptr()) };
+        match ret {
+            0 => Err(TessBaseApiPrintVariablesToFileError {}),
+            _ => Ok(()),
+        }
+    }
+
+    /// Get every Tesseract parameter and its current value as `(name, value)` pairs.
+    ///
+    /// The C API can only print the parameters to a file,
+    /// so this prints them to a newly created temporary file that is read back and removed.
+    pub fn get_variables(&self) -> Result<Vec<(String, String)>, TessBaseApiGetVariablesError> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let mut attempts = 0;
+        // Create the file exclusively, so an existing file or symlink at the path is never written through.
+        let path = loop {
+            let path = env::temp_dir().join(format!(
+                "tesseract-plumbing-variables-{}-{}.txt",
+                process::id(),
+                COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
+            match fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(_) => break path,
+                Err(error) if error.kind() == io::ErrorKind::AlreadyExists && attempts < 100 => {
+                    attempts += 1
+                }
+                Err(error) => return Err(error.into()),
+            }
+        };
+        let contents = path
+            .to_str()
+            .and_then(|path| CString::new(path).ok())
+            .ok_or(TessBaseApiGetVariablesError::TemporaryFilePath())
+            .and_then(|filename| {
+                self.print_variables_to_file(&filename)?;
+                Ok(fs::read_to_string(&path)?)
+            });
+        let _ = fs::remove_file(&path);
+        Ok(contents?
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\t');
+                Some((fields.next()?.to_owned(), fields.next()?.to_owned()))
+            })
+            .collect())
+    }
+
     /// Wrapper for [`SetPageSegMode`](https://tesseract-ocr.github.io/tessapi/5.x/a02438.html#a15a7a9c1afbba3078a55b4566de891ab)
     ///
     /// Set the current page segmentation mode
     pub fn set_page_seg_mode(&mut self, mode: TessPageSegMode) {
         unsafe { TessBaseAPISetPageSegMode(self.0, mode) };
+        self.1 = true;
+    }
+
+    /// Wrapper for [`GetPageSegMode`](https://tesseract-ocr.github.io/tessapi/5.x/a02438.html#a61c5592b57a1b776416e643e2719a751)
+    ///
+    /// Get the current page segmentation mode.
+    /// Returns `None` before `init_*`, `set_variable` or `set_page_seg_mode` has been called,
+    /// or if `tessedit_pageseg_mode` has been set to an unknown value.
+    pub fn get_page_seg_mode(&self) -> Option<PageSegMode> {
+        if !self.1 {
+            return None;
+        }
+        PageSegMode::try_from(unsafe { TessBaseAPIGetPageSegMode(self.0) }).ok()
+    }
+
+    /// Wrapper for [`TessBaseAPIAnalyseLayout`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#ad1456400ac29e2999f5b630b37372374)
+    ///
+    /// Run page layout analysis without recognition. Returns `None` if no blocks were found.
+    ///
+    /// Similar words are never merged: the C API always passes `merge_similar_words = false` to the C++ `AnalyseLayout`,
+    /// and no Tesseract variable controls the merging.
+    pub fn analyse_layout(&mut self) -> Option<PageIterator<'_>> {
+        let ptr = unsafe { TessBaseAPIAnalyseLayout(self.0) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { PageIterator::new(ptr) })
+        }
     }
 
     /// Wrapper for [`Recognize`](https://tesseract-ocr.github.io/tessapi/5.x/a02438.html#a0e4065c20b142d69a2324ee0c74ae0b0)
@@ -291,7 +732,7 @@ impl TessBaseApi {
     /// Recognize the image. Returns `Ok(())` on success and `Err(())` otherwise.
     /// It is currently unclear to me what would make it error.
     ///
-    /// It could take a progress argument (`monitor`). If there is appetite for this, let me know and I could try and implement it.
+    /// Use `recognize_with_monitor` to track progress or cancel recognition.
     pub fn recognize(&mut self) -> Result<(), TessBaseApiRecogniseError> {
         let ret = unsafe { TessBaseAPIRecognize(self.0, ptr::null_mut()) };
         match ret {
@@ -299,6 +740,115 @@ impl TessBaseApi {
             _ => Err(TessBaseApiRecogniseError {}),
         }
     }
+
+    /// Wrapper for [`Recognize`](https://tesseract-ocr.github.io/tessapi/5.x/a02438.html#a0e4065c20b142d69a2324ee0c74ae0b0)
+    ///
+    /// Recognize the image, reporting progress to `monitor`.
+    /// Returns an error if recognition failed, was cancelled or exceeded the monitor's deadline.
+    pub fn recognize_with_monitor(
+        &mut self,
+        monitor: &mut Monitor,
+    ) -> Result<(), TessBaseApiRecogniseError> {
+        let ret = unsafe { TessBaseAPIRecognize(self.0, monitor.as_mut_ptr()) };
+        match ret {
+            0 => Ok(()),
+            _ => Err(TessBaseApiRecogniseError {}),
+        }
+    }
+
+    /// Recognize the image and collect the results into a `Page`,
+    /// with the text, confidence and attributes of every block, paragraph, line, word and symbol.
+    pub fn recognize_to_page(&mut self) -> Result<Page, TessBaseApiRecogniseError> {
+        self.recognize()?;
+        Ok(self
+            .get_iterator()
+            .map(|mut iterator| Page::from_iterator(&mut iterator))
+            .unwrap_or_default())
+    }
+
+    /// Wrapper for [`TessBaseAPIGetIterator`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a1abbf4ed5199b8eacec92b479c7ef5ce)
+    ///
+    /// Get an iterator over the recognition results. Returns `None` if `recognize` hasn't been called or failed.
+    ///
+    /// The iterator borrows the API, so the results can't be changed whilst it exists.
+    pub fn get_iterator(&mut self) -> Option<ResultIterator<'_>> {
+        let ptr = unsafe { TessBaseAPIGetIterator(self.0) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { ResultIterator::new(ptr) })
+        }
+    }
+
+    /// Wrapper for [`TessBaseAPIProcessPages`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a226b6f71811742287a59616dc47f9732)
+    ///
+    /// Recognize every page of `filename`, which may be a multi-page TIFF, a single image or a text file listing images.
+    /// Pages that fail are retried with the `retry_config` config file if given.
+    /// `timeout_millisec` limits the time spent on each page, with 0 meaning no limit.
+    ///
+    /// If a `renderer` is given, the document is begun, each page added and the document ended.
+    pub fn process_pages(
+        &mut self,
+        filename: &CStr,
+        retry_config: Option<&CStr>,
+        timeout_millisec: c_int,
+        renderer: Option<&mut ResultRenderer>,
+    ) -> Result<(), TessBaseApiProcessPagesError> {
+        let ptr = unsafe {
+            TessBaseAPIProcessPages(
+                self.0,
+                filename.as_ptr(),
+                retry_config.map(CStr::as_ptr).unwrap_or_else(ptr::null),
+                timeout_millisec,
+                renderer
+                    .map(ResultRenderer::as_mut_ptr)
+                    .unwrap_or_else(ptr::null_mut),
+            )
+        };
+        if ptr.is_null() {
+            Err(TessBaseApiProcessPagesError {})
+        } else {
+            // The returned string carries no recognition results, only success.
+            unsafe { TessDeleteText(ptr) };
+            Ok(())
+        }
+    }
+
+    /// Wrapper for [`TessBaseAPIProcessPage`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a1ed0927fb978b503004d159125de44be)
+    ///
+    /// Recognize a single page, where `page_index` and `filename` identify the page within its document.
+    /// Pages that fail are retried with the `retry_config` config file if given.
+    /// `timeout_millisec` limits the time spent on the page, with 0 meaning no limit.
+    ///
+    /// If a `renderer` is given the page is added to it, but the caller must begin and end the document.
+    pub fn process_page(
+        &mut self,
+        pix: &Pix,
+        page_index: c_int,
+        filename: &CStr,
+        retry_config: Option<&CStr>,
+        timeout_millisec: c_int,
+        renderer: Option<&mut ResultRenderer>,
+    ) -> Result<(), TessBaseApiProcessPageError> {
+        let ret = unsafe {
+            TessBaseAPIProcessPage(
+                self.0,
+                *pix.as_ref(),
+                page_index,
+                filename.as_ptr(),
+                retry_config.map(CStr::as_ptr).unwrap_or_else(ptr::null),
+                timeout_millisec,
+                renderer
+                    .map(ResultRenderer::as_mut_ptr)
+                    .unwrap_or_else(ptr::null_mut),
+            )
+        };
+        match ret {
+            0 => Err(TessBaseApiProcessPageError {}),
+            _ => Ok(()),
+        }
+    }
+
     /// Wrapper for [`GetUTF8Text`](https://tesseract-ocr.github.io/tessapi/5.x/a02438.html#a115ef656f83352ba608b4f0bf9cfa2c4)
     ///
     /// Get the text out of an image.
@@ -347,6 +897,26 @@ impl TessBaseApi {
         }
     }
 
+    /// Wrapper for [`TessBaseAPIGetThresholdedImage`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#aa97cfb85d161ff12cd92013a93994aeb)
+    ///
+    /// Get the binary image Tesseract recognizes from, thresholding the input image first if needed.
+    /// Returns `None` before initialization or if there's no image.
+    pub fn get_thresholded_image(&mut self) -> Option<RefCounted<Pix>> {
+        let ptr = unsafe { TessBaseAPIGetThresholdedImage(self.0) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(unsafe { RefCounted::new(Pix::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`TessBaseAPIGetThresholdedImageScaleFactor`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a1a7269a6236f9452b6b88bc910643630)
+    ///
+    /// Returns the factor the thresholded image was scaled down by relative to the input image, or 0 if there's no thresholded image.
+    pub fn get_thresholded_image_scale_factor(&self) -> c_int {
+        unsafe { TessBaseAPIGetThresholdedImageScaleFactor(self.0) }
+    }
+
     /// Wrapper for [`TessBaseAPIGetSourceYResolution`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a2996381d53d41e486b7fb77e071df8ad)
     pub fn get_source_y_resolution(&self) -> c_int {
         unsafe { TessBaseAPIGetSourceYResolution(self.0) }
@@ -418,6 +988,66 @@ impl TessBaseApi {
         }
     }
 
+    /// Wrapper for [`TessBaseAPIGetOsdText`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#af8270b51a08e1731ccbc77fd094e111b)
+    ///
+    /// Make an OSD (orientation and script detection) report, as written by `tesseract --psm 0`. page_number is a 0-based page index that will appear in the report.
+    ///
+    /// Returns an error if no image has been set, as Tesseract would crash.
+    pub fn get_osd_text(&mut self, page_number: c_int) -> Result<Text, TessBaseApiGetOsdTextError> {
+        if self.get_input_image().is_none() {
+            return Err(TessBaseApiGetOsdTextError {});
+        }
+        let ptr = unsafe { TessBaseAPIGetOsdText(self.0, page_number) };
+        if ptr.is_null() {
+            Err(TessBaseApiGetOsdTextError {})
+        } else {
+            Ok(unsafe { Text::new(ptr) })
+        }
+    }
+
+    /// Wrapper for [`TessBaseAPIDetectOrientationScript`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a85951a3f243ee0426ac796ed5eff676a)
+    ///
+    /// Detect the orientation and script of the current image.
+    /// This needs the `osd` language to be loaded and enough text in the image.
+    ///
+    /// Returns an error if no image has been set, as Tesseract would crash.
+    pub fn detect_orientation_script(
+        &mut self,
+    ) -> Result<OrientationScript, TessBaseApiDetectOrientationScriptError> {
+        if self.get_input_image().is_none() {
+            return Err(TessBaseApiDetectOrientationScriptError {});
+        }
+        let mut orientation_degrees = 0;
+        let mut orientation_confidence = 0.0;
+        let mut script_name = ptr::null();
+        let mut script_confidence = 0.0;
+        let ret = unsafe {
+            TessBaseAPIDetectOrientationScript(
+                self.0,
+                &mut orientation_degrees,
+                &mut orientation_confidence,
+                &mut script_name,
+                &mut script_confidence,
+            )
+        };
+        if ret == 0 {
+            return Err(TessBaseApiDetectOrientationScriptError {});
+        }
+        let script_name = if script_name.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(script_name) }
+                .to_string_lossy()
+                .into_owned()
+        };
+        Ok(OrientationScript {
+            orientation_degrees,
+            orientation_confidence,
+            script_name,
+            script_confidence,
+        })
+    }
+
     /// Wrapper for [`TessBaseAPIMeanTextConf`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a20c2c34197abc55043cb23be4e332ad0)
     ///
     /// Returns the (average) confidence value between 0 and 100.
@@ -451,7 +1081,22 @@ impl TessBaseApi {
     /// Wrapper for [`GetComponentImages 1/2`](https://tesseract-ocr.github.io/tessapi/5.x/a02438.html#ad74ae1266a5299734ec6f5225b6cb5c1)
     ///
     /// Get the given level kind of components (block, textline, word etc.) as a leptonica-style Boxa, Pixa pair, in reading order.
+    /// If `text_only` is true, only text components are returned.
     pub fn get_component_images_1(
+        &self,
+        level: PageIteratorLevel,
+        text_only: bool,
+    ) -> Result<
+        leptonica_plumbing::memory::RefCountedExclusive<leptonica_plumbing::Boxa>,
+        TessBaseApiGetComponentImagesError,
+    > {
+        self.get_component_images_1_raw(level.into(), text_only as c_int)
+    }
+
+    /// Wrapper for [`GetComponentImages 1/2`](https://tesseract-ocr.github.io/tessapi/5.x/a02438.html#ad74ae1266a5299734ec6f5225b6cb5c1)
+    ///
+    /// Like `get_component_images_1`, but takes the raw `TessPageIteratorLevel` and `text_only` flag.
+    pub fn get_component_images_1_raw(
         &self,
         level: TessPageIteratorLevel,
         text_only: c_int,
@@ -478,6 +1123,109 @@ impl TessBaseApi {
             })
         }
     }
+
+    /// Wrapper for [`TessBaseAPIGetComponentImages1`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#ae54ece8f80538d60705d7678f70e8ac2)
+    ///
+    /// Get the given level kind of components along with their cropped images and the blocks and paragraphs they belong to.
+    /// If `text_only` is true, only text components are returned.
+    /// If `raw_image` is true, the images are cropped from the original image instead of the thresholded one,
+    /// with `raw_padding` pixels of padding around each component.
+    pub fn get_component_images(
+        &self,
+        level: PageIteratorLevel,
+        text_only: bool,
+        raw_image: bool,
+        raw_padding: c_int,
+    ) -> Result<ComponentImages, TessBaseApiGetComponentImagesError> {
+        let mut pixa = ptr::null_mut();
+        let mut block_ids = ptr::null_mut();
+        let mut para_ids = ptr::null_mut();
+        let ptr = unsafe {
+            TessBaseAPIGetComponentImages1(
+                self.0,
+                level.into(),
+                text_only as c_int,
+                raw_image as c_int,
+                raw_padding,
+                &mut pixa,
+                &mut block_ids,
+                &mut para_ids,
+            )
+        };
+        if ptr.is_null() {
+            return Err(TessBaseApiGetComponentImagesError {});
+        }
+        let boxes = unsafe { RefCountedExclusive::new(Boxa::new_from_pointer(ptr)) };
+        let len = boxes.get_count().max(0) as usize;
+        Ok(ComponentImages {
+            images: unsafe { RefCountedExclusive::new(Pixa::new_from_pointer(pixa)) },
+            block_ids: unsafe { take_int_array(block_ids, len) },
+            para_ids: unsafe { take_int_array(para_ids, len) },
+            boxes,
+        })
+    }
+
+    /// Wrapper for [`TessBaseAPIGetRegions`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a3ebb3310a53d0c56220a49773f2b5d6c)
+    ///
+    /// Get the blocks of the page layout analysis, in reading order.
+    pub fn get_regions(&self) -> Result<RefCountedExclusive<Boxa>, TessBaseApiGetRegionsError> {
+        let ptr = unsafe { TessBaseAPIGetRegions(self.0, ptr::null_mut()) };
+        if ptr.is_null() {
+            Err(TessBaseApiGetRegionsError {})
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Boxa::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`TessBaseAPIGetTextlines`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a00aa8a6f353f53269fa552c4406f55b1)
+    ///
+    /// Get the text lines, in reading order.
+    pub fn get_textlines(&self) -> Result<RefCountedExclusive<Boxa>, TessBaseApiGetTextlinesError> {
+        let ptr = unsafe { TessBaseAPIGetTextlines(self.0, ptr::null_mut(), ptr::null_mut()) };
+        if ptr.is_null() {
+            Err(TessBaseApiGetTextlinesError {})
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Boxa::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`TessBaseAPIGetStrips`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a3b295ad25016363e1977dbf966e7eca6)
+    ///
+    /// Get the text lines as strips covering the full width of their block, in reading order.
+    pub fn get_strips(&self) -> Result<RefCountedExclusive<Boxa>, TessBaseApiGetStripsError> {
+        let ptr = unsafe { TessBaseAPIGetStrips(self.0, ptr::null_mut(), ptr::null_mut()) };
+        if ptr.is_null() {
+            Err(TessBaseApiGetStripsError {})
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Boxa::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`TessBaseAPIGetWords`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a50270e54645b87d51a16924859002ad0)
+    ///
+    /// Get the words, in reading order.
+    pub fn get_words(&self) -> Result<RefCountedExclusive<Boxa>, TessBaseApiGetWordsError> {
+        let ptr = unsafe { TessBaseAPIGetWords(self.0, ptr::null_mut()) };
+        if ptr.is_null() {
+            Err(TessBaseApiGetWordsError {})
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Boxa::new_from_pointer(ptr)) })
+        }
+    }
+
+    /// Wrapper for [`TessBaseAPIGetConnectedComponents`](https://tesseract-ocr.github.io/tessapi/5.x/a00008.html#a878d6f61dcd279009d86b37c33d52392)
+    ///
+    /// Get the connected components of the text, such as individual characters, in reading order.
+    pub fn get_connected_components(
+        &self,
+    ) -> Result<RefCountedExclusive<Boxa>, TessBaseApiGetConnectedComponentsError> {
+        let ptr = unsafe { TessBaseAPIGetConnectedComponents(self.0, ptr::null_mut()) };
+        if ptr.is_null() {
+            Err(TessBaseApiGetConnectedComponentsError {})
+        } else {
+            Ok(unsafe { RefCountedExclusive::new(Boxa::new_from_pointer(ptr)) })
+        }
+    }
 }
 
 #[test]
@@ -519,3 +1267,313 @@ fn set_variable_error_test() -> Result<(), Box<dyn std::error::Error>> {
     assert!(tess.set_variable(&fail, &fail).is_err());
     Ok(())
 }
+
+#[test]
+fn get_variable_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tess = TessBaseApi::create();
+    assert_eq!(
+        tess.get_int_variable(&CString::new("tessedit_pageseg_mode")?),
+        None
+    );
+    tess.init_2(None, None)?;
+
+    let int_name = CString::new("tessedit_pageseg_mode")?;
+    tess.set_variable(&int_name, &CString::new("7")?)?;
+    assert_eq!(tess.get_int_variable(&int_name), Some(7));
+
+    let bool_name = CString::new("tessedit_create_hocr")?;
+    tess.set_variable(&bool_name, &CString::new("1")?)?;
+    assert_eq!(tess.get_bool_variable(&bool_name), Some(true));
+
+    let double_name = CString::new("min_orientation_margin")?;
+    tess.set_variable(&double_name, &CString::new("2.5")?)?;
+    assert_eq!(tess.get_double_variable(&double_name), Some(2.5));
+
+    let string_name = CString::new("tessedit_char_whitelist")?;
+    tess.set_variable(&string_name, &CString::new("abc")?)?;
+    assert_eq!(
+        tess.get_string_variable(&string_name),
+        Some(CString::new("abc")?.as_c_str())
+    );
+
+    let fail = CString::new("fail")?;
+    assert_eq!(tess.get_int_variable(&fail), None);
+    assert_eq!(tess.get_bool_variable(&fail), None);
+    assert_eq!(tess.get_double_variable(&fail), None);
+    assert_eq!(tess.get_string_variable(&fail), None);
+    Ok(())
+}
+
+#[test]
+fn get_iterator_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (mut tess, _pix) = crate::test_api()?;
+    assert!(tess.get_iterator().is_none());
+    tess.recognize()?;
+    let mut iterator = tess.get_iterator().unwrap();
+    let level = PageIteratorLevel::Word;
+    assert_eq!(
+        iterator.get_utf8_text(level)?.as_ref().to_str()?,
+        "tesseract_plumbing"
+    );
+    assert!(iterator.confidence(level) > 0.0);
+    let (left, top, right, bottom) = iterator.bounding_box(level).unwrap();
+    assert!(left < right && top < bottom);
+    assert!(!iterator.next(level));
+    Ok(())
+}
+
+#[test]
+fn analyse_layout_test() -> Result<(), Box<dyn std::error::Error>> {
+    use self::tesseract_sys::{
+        TessPolyBlockType_PT_CAPTION_TEXT, TessPolyBlockType_PT_FLOWING_TEXT,
+        TessPolyBlockType_PT_HEADING_TEXT, TessPolyBlockType_PT_PULLOUT_TEXT,
+        TessPolyBlockType_PT_VERTICAL_TEXT,
+    };
+
+    let mut tess = TessBaseApi::create();
+    tess.init_for_analyse_page();
+    let pix = leptonica_plumbing::Pix::read_mem(include_bytes!("../image.png"))?;
+    tess.set_image_2(&pix);
+    let mut iterator = tess.analyse_layout().unwrap();
+    let mut text_blocks = 0;
+    loop {
+        if [
+            TessPolyBlockType_PT_FLOWING_TEXT,
+            TessPolyBlockType_PT_HEADING_TEXT,
+            TessPolyBlockType_PT_PULLOUT_TEXT,
+            TessPolyBlockType_PT_VERTICAL_TEXT,
+            TessPolyBlockType_PT_CAPTION_TEXT,
+        ]
+        .contains(&iterator.block_type())
+        {
+            assert!(iterator.bounding_box(PageIteratorLevel::Block).is_some());
+            text_blocks += 1;
+        }
+        if !iterator.next(PageIteratorLevel::Block) {
+            break;
+        }
+    }
+    assert!(text_blocks >= 1);
+    Ok(())
+}
+
+#[test]
+fn recognize_with_monitor_test() -> Result<(), Box<dyn std::error::Error>> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    let (mut tess, pix) = crate::test_api()?;
+
+    let mut monitor = Monitor::create();
+    tess.recognize_with_monitor(&mut monitor)?;
+    assert!((0..=100).contains(&monitor.get_progress()));
+
+    let called = Arc::new(AtomicBool::new(false));
+    let called_in_callback = called.clone();
+    let mut monitor = Monitor::create();
+    monitor.set_cancel_func(move |_words| {
+        called_in_callback.store(true, Ordering::SeqCst);
+        true
+    });
+    tess.set_image_2(&pix);
+    assert!(tess.recognize_with_monitor(&mut monitor).is_err());
+    assert!(called.load(Ordering::SeqCst));
+
+    let mut monitor = Monitor::create();
+    monitor.set_cancel_func(|_words| panic!("cancel callback panicked"));
+    tess.set_image_2(&pix);
+    assert!(tess.recognize_with_monitor(&mut monitor).is_err());
+    Ok(())
+}
+
+#[test]
+fn get_variables_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tess = TessBaseApi::create();
+    assert!(tess.get_variables().is_err());
+    let prefix = format!("tesseract-plumbing-variables-{}-", process::id());
+    assert!(!fs::read_dir(env::temp_dir())?
+        .filter_map(Result::ok)
+        .any(|entry| entry.file_name().to_string_lossy().starts_with(&prefix)));
+    tess.init_2(None, None)?;
+    tess.set_variable(
+        &CString::new("tessedit_char_whitelist")?,
+        &CString::new("abc")?,
+    )?;
+    let variables = tess.get_variables()?;
+    assert!(variables.contains(&("tessedit_char_whitelist".to_owned(), "abc".to_owned())));
+    assert!(variables
+        .iter()
+        .any(|(name, _)| name == "tessedit_pageseg_mode"));
+    Ok(())
+}
+
+#[test]
+fn init_with_config_test() -> Result<(), Box<dyn std::error::Error>> {
+    let load_system_dawg = CString::new("load_system_dawg")?;
+    let load_freq_dawg = CString::new("load_freq_dawg")?;
+    let off = CString::new("0")?;
+    let mut tess = TessBaseApi::create();
+    tess.init_with_config(
+        None,
+        None,
+        OcrEngineMode::Default.into(),
+        &[],
+        &[(&load_system_dawg, &off), (&load_freq_dawg, &off)],
+        false,
+    )?;
+    assert_eq!(tess.get_bool_variable(&load_system_dawg), Some(false));
+    assert_eq!(tess.get_bool_variable(&load_freq_dawg), Some(false));
+    Ok(())
+}
+
+#[test]
+fn get_languages_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tess = TessBaseApi::create();
+    assert!(tess.get_init_languages().is_empty());
+    assert!(tess.get_available_languages().is_empty());
+    tess.init_2(None, Some(&CString::new("eng")?))?;
+    assert_eq!(tess.get_init_languages(), vec!["eng".to_owned()]);
+    assert_eq!(tess.get_loaded_languages(), vec!["eng".to_owned()]);
+    assert!(tess.get_available_languages().contains(&"eng".to_owned()));
+    Ok(())
+}
+
+#[test]
+fn process_pages_test() -> Result<(), Box<dyn std::error::Error>> {
+    let outputbase = env::temp_dir().join(format!(
+        "tesseract-plumbing-process-pages-test-{}",
+        process::id()
+    ));
+    let mut tess = TessBaseApi::create();
+    tess.init_2(None, None)?;
+
+    let mut renderer = ResultRenderer::create_text(&CString::new(outputbase.to_str().unwrap())?);
+    tess.process_pages(&CString::new("image.png")?, None, 0, Some(&mut renderer))?;
+    drop(renderer);
+    let text = fs::read_to_string(outputbase.with_extension("txt"))?;
+    fs::remove_file(outputbase.with_extension("txt"))?;
+    assert!(text.contains("tesseract_plumbing"));
+
+    let pix = leptonica_plumbing::Pix::read_mem(include_bytes!("../image.png"))?;
+    tess.process_page(&pix, 0, &CString::new("image.png")?, None, 0, None)?;
+    assert!(tess
+        .get_utf8_text()?
+        .as_ref()
+        .to_str()?
+        .contains("tesseract_plumbing"));
+
+    assert!(tess
+        .process_pages(&CString::new("fail")?, None, 0, None)
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn page_seg_mode_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tess = TessBaseApi::create();
+    assert_eq!(tess.get_page_seg_mode(), None);
+    tess.init_2(None, None)?;
+    tess.set_page_seg_mode(PageSegMode::SingleLine.into());
+    assert_eq!(tess.get_page_seg_mode(), Some(PageSegMode::SingleLine));
+    Ok(())
+}
+
+#[test]
+fn get_component_images_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (tess, _pix) = crate::test_api()?;
+    let words = tess.get_component_images_1(PageIteratorLevel::Word, true)?;
+    assert_eq!(words.get_count(), 1);
+    let raw_words =
+        tess.get_component_images_1_raw(tesseract_sys::TessPageIteratorLevel_RIL_WORD, 1)?;
+    assert_eq!(raw_words.get_count(), 1);
+    Ok(())
+}
+
+#[test]
+fn get_component_images_with_ids_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (tess, _pix) = crate::test_api()?;
+    let components = tess.get_component_images(PageIteratorLevel::Symbol, true, true, 2)?;
+    let count = components.boxes.get_count();
+    assert!(count > 1);
+    assert_eq!(components.images.get_count(), count);
+    assert_eq!(components.block_ids.len(), count as usize);
+    assert_eq!(components.para_ids.len(), count as usize);
+    assert!(components.block_ids.iter().all(|&id| id == 0));
+    Ok(())
+}
+
+#[test]
+fn segmentation_boxes_test() -> Result<(), Box<dyn std::error::Error>> {
+    let (tess, _pix) = crate::test_api()?;
+    assert!(tess.get_regions()?.get_count() >= 1);
+    assert_eq!(tess.get_textlines()?.get_count(), 1);
+    assert_eq!(tess.get_strips()?.get_count(), 1);
+    assert_eq!(tess.get_words()?.get_count(), 1);
+    assert!(tess.get_connected_components()?.get_count() > 1);
+    Ok(())
+}
+
+#[test]
+fn get_thresholded_image_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tess = TessBaseApi::create();
+    tess.init_2(None, None)?;
+    assert!(tess.get_thresholded_image().is_none());
+    assert_eq!(tess.get_thresholded_image_scale_factor(), 0);
+    let pix = leptonica_plumbing::Pix::read_mem(include_bytes!("../image.png"))?;
+    tess.set_image_2(&pix);
+    let thresholded = tess.get_thresholded_image().unwrap();
+    assert_eq!(thresholded.get_depth(), 1);
+    assert_eq!(thresholded.get_width(), pix.get_width());
+    assert_eq!(thresholded.get_height(), pix.get_height());
+    assert_eq!(tess.get_thresholded_image_scale_factor(), 1);
+    Ok(())
+}
+
+#[test]
+fn detect_orientation_script_error_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tess = TessBaseApi::create();
+    assert!(tess.detect_orientation_script().is_err());
+    assert!(tess.get_osd_text(0).is_err());
+    tess.init_2(None, None)?;
+    assert!(tess.detect_orientation_script().is_err());
+    assert!(tess.get_osd_text(0).is_err());
+    Ok(())
+}
+
+#[test]
+fn detect_orientation_script_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tess = TessBaseApi::create();
+    tess.init_2(None, None)?;
+    if !tess
+        .get_available_languages()
+        .iter()
+        .any(|lang| lang == "osd")
+    {
+        return Ok(());
+    }
+    tess.init_2(None, Some(&CString::new("osd")?))?;
+
+    // Detection needs more text than one word, so stack the image a few times.
+    let word = image::open("image.png")?.to_luma8();
+    let mut img = image::GrayImage::new(word.width(), word.height() * 8);
+    for row in 0..8 {
+        image::imageops::replace(&mut img, &word, 0, (row * word.height()).into());
+    }
+    tess.set_image(
+        img.as_raw(),
+        img.width().try_into()?,
+        img.height().try_into()?,
+        1,
+        img.width().try_into()?,
+    )?;
+
+    let orientation_script = tess.detect_orientation_script()?;
+    assert_eq!(orientation_script.orientation_degrees, 0);
+    assert_eq!(orientation_script.script_name, "Latin");
+    assert!(tess
+        .get_osd_text(0)?
+        .as_ref()
+        .to_str()?
+        .contains("Script: Latin"));
+    Ok(())
+}
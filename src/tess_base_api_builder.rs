@@ -0,0 +1,241 @@
+extern crate tesseract_sys;
+extern crate thiserror;
+
+use self::thiserror::Error;
+use crate::{
+    OcrEngineMode, PageSegMode, TessBaseApi, TessBaseApiInitError, TessBaseApiSetVariableError,
+};
+use std::ffi::{CStr, CString};
+use std::os::raw::c_int;
+
+/// Collects the settings needed to create and initialize a `TessBaseApi` in one call.
+///
+/// This sequences `create`, `init_with_config`, `set_variable` and `set_page_seg_mode`.
+#[derive(Debug, Clone, Default)]
+pub struct TessBaseApiBuilder {
+    datapath: Option<String>,
+    languages: Vec<String>,
+    oem: Option<OcrEngineMode>,
+    page_seg_mode: Option<PageSegMode>,
+    init_variables: Vec<(String, String)>,
+    variables: Vec<(String, String)>,
+    source_resolution: Option<c_int>,
+}
+
+#[derive(Debug, Error)]
+pub enum TessBaseApiBuildError {
+    #[error("TessBaseApiBuilder {field} {value:?} contains a nul byte")]
+    InvalidString { field: &'static str, value: String },
+    #[error(
+        "TessBaseApi failed to initialize with datapath {datapath:?} and language {language:?}"
+    )]
+    Init {
+        datapath: Option<String>,
+        language: Option<String>,
+        #[source]
+        source: TessBaseApiInitError,
+    },
+    #[error("TessBaseApi failed to set variable {name:?} to {value:?}")]
+    SetVariable {
+        name: String,
+        value: String,
+        #[source]
+        source: TessBaseApiSetVariableError,
+    },
+}
+
+fn c_string(field: &'static str, value: &str) -> Result<CString, TessBaseApiBuildError> {
+    CString::new(value).map_err(|_| TessBaseApiBuildError::InvalidString {
+        field,
+        value: value.to_owned(),
+    })
+}
+
+fn c_string_pairs(
+    field: &'static str,
+    pairs: &[(String, String)],
+) -> Result<Vec<(CString, CString)>, TessBaseApiBuildError> {
+    pairs
+        .iter()
+        .map(|(name, value)| Ok((c_string(field, name)?, c_string(field, value)?)))
+        .collect()
+}
+
+impl TessBaseApiBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The directory containing `tessdata`. Tesseract's default is used if this isn't set.
+    pub fn datapath(mut self, datapath: impl Into<String>) -> Self {
+        self.datapath = Some(datapath.into());
+        self
+    }
+
+    /// Add a language to load. Multiple languages are joined with `+`.
+    pub fn language(mut self, language: impl Into<String>) -> Self {
+        self.languages.push(language.into());
+        self
+    }
+
+    /// Add languages to load. Multiple languages are joined with `+`.
+    pub fn languages<I>(mut self, languages: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.languages.extend(languages.into_iter().map(Into::into));
+        self
+    }
+
+    /// The OCR engine mode. Defaults to `OcrEngineMode::Default`.
+    pub fn oem(mut self, oem: OcrEngineMode) -> Self {
+        self.oem = Some(oem);
+        self
+    }
+
+    /// The page segmentation mode to set after initialization.
+    pub fn page_seg_mode(mut self, mode: PageSegMode) -> Self {
+        self.page_seg_mode = Some(mode);
+        self
+    }
+
+    /// A variable that can only be set during initialization, such as `load_system_dawg`.
+    pub fn init_variable(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.init_variables.push((name.into(), value.into()));
+        self
+    }
+
+    /// A variable to set with `set_variable` after initialization.
+    pub fn variable(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.variables.push((name.into(), value.into()));
+        self
+    }
+
+    /// The resolution of source images in pixels per inch.
+    ///
+    /// `set_source_resolution` needs an image, so this sets the `user_defined_dpi` variable instead.
+    pub fn source_resolution(mut self, ppi: c_int) -> Self {
+        self.source_resolution = Some(ppi);
+        self
+    }
+
+    /// Create and initialize the `TessBaseApi`.
+    pub fn build(self) -> Result<TessBaseApi, TessBaseApiBuildError> {
+        let datapath = self
+            .datapath
+            .as_deref()
+            .map(|datapath| c_string("datapath", datapath))
+            .transpose()?;
+        let language = if self.languages.is_empty() {
+            None
+        } else {
+            Some(self.languages.join("+"))
+        };
+        let c_language = language
+            .as_deref()
+            .map(|language| c_string("language", language))
+            .transpose()?;
+        let init_variables = c_string_pairs("init variable", &self.init_variables)?;
+        let init_variables: Vec<(&CStr, &CStr)> = init_variables
+            .iter()
+            .map(|(name, value)| (name.as_c_str(), value.as_c_str()))
+            .collect();
+        let mut variables = c_string_pairs("variable", &self.variables)?;
+        if let Some(ppi) = self.source_resolution {
+            variables.push((
+                c_string("variable", "user_defined_dpi")?,
+                c_string("variable", &ppi.to_string())?,
+            ));
+        }
+
+        let mut api = TessBaseApi::create();
+        api.init_with_config(
+            datapath.as_deref(),
+            c_language.as_deref(),
+            self.oem.unwrap_or_default().into(),
+            &[],
+            &init_variables,
+            false,
+        )
+        .map_err(|source| TessBaseApiBuildError::Init {
+            datapath: self.datapath.clone(),
+            language,
+            source,
+        })?;
+        for (name, value) in &variables {
+            api.set_variable(name, value)
+                .map_err(|source| TessBaseApiBuildError::SetVariable {
+                    name: name.to_string_lossy().into_owned(),
+                    value: value.to_string_lossy().into_owned(),
+                    source,
+                })?;
+        }
+        if let Some(mode) = self.page_seg_mode {
+            api.set_page_seg_mode(mode.into());
+        }
+        Ok(api)
+    }
+}
+
+#[test]
+fn build_test() -> Result<(), Box<dyn std::error::Error>> {
+    let api = TessBaseApiBuilder::new()
+        .languages(vec!["eng"])
+        .oem(OcrEngineMode::LstmOnly)
+        .init_variable("load_system_dawg", "0")
+        .variable("tessedit_char_whitelist", "abc")
+        .page_seg_mode(PageSegMode::SingleLine)
+        .source_resolution(300)
+        .build()?;
+    assert_eq!(
+        api.get_bool_variable(&CString::new("load_system_dawg")?),
+        Some(false)
+    );
+    assert_eq!(
+        api.get_string_variable(&CString::new("tessedit_char_whitelist")?),
+        Some(CString::new("abc")?.as_c_str())
+    );
+    assert_eq!(
+        api.get_int_variable(&CString::new("user_defined_dpi")?),
+        Some(300)
+    );
+    assert_eq!(api.get_page_seg_mode(), Some(PageSegMode::SingleLine));
+    assert_eq!(api.oem()?, OcrEngineMode::LstmOnly);
+    Ok(())
+}
+
+#[test]
+fn build_source_resolution_test() -> Result<(), Box<dyn std::error::Error>> {
+    let mut api = TessBaseApiBuilder::new()
+        .source_resolution(300)
+        .variable("tessedit_char_blacklist", "xyz")
+        .build()?;
+    assert_eq!(
+        api.get_int_variable(&CString::new("user_defined_dpi")?),
+        Some(300)
+    );
+    assert_eq!(
+        api.get_string_variable(&CString::new("tessedit_char_blacklist")?),
+        Some(CString::new("xyz")?.as_c_str())
+    );
+    let pix = leptonica_plumbing::Pix::read_mem(include_bytes!("../image.png"))?;
+    api.set_image_2(&pix);
+    api.recognize()?;
+    assert_eq!(api.get_source_y_resolution(), 300);
+    Ok(())
+}
+
+#[test]
+fn build_error_test() {
+    match TessBaseApiBuilder::new().variable("fail", "fail").build() {
+        Err(TessBaseApiBuildError::SetVariable { name, .. }) => assert_eq!(name, "fail"),
+        other => panic!("unexpected result {:?}", other.map(|_| ())),
+    }
+    match TessBaseApiBuilder::new().language("fail").build() {
+        Err(TessBaseApiBuildError::Init { language, .. }) => {
+            assert_eq!(language.as_deref(), Some("fail"))
+        }
+        other => panic!("unexpected result {:?}", other.map(|_| ())),
+    }
+}